@@ -0,0 +1,50 @@
+#![feature(test)]
+extern crate test;
+
+use minhash_rs::prelude::*;
+
+use test::{black_box, Bencher};
+
+const NUMBER_OF_MINHASHES: u64 = 5_000;
+
+fn minhashes() -> (MinHash<u64, 128>, Vec<MinHash<u64, 128>>) {
+    let query = (0..1000_u64).collect();
+    let others = (0..NUMBER_OF_MINHASHES)
+        .map(|i| (i * 10..i * 10 + 1000).collect())
+        .collect();
+    (query, others)
+}
+
+#[bench]
+fn bench_naive_loop_estimate_jaccard_index(b: &mut Bencher) {
+    let (query, others) = minhashes();
+
+    b.iter(|| {
+        // Inner closure, the actual test
+        let estimates: Vec<f64> = others
+            .iter()
+            .map(|other| query.estimate_jaccard_index(other))
+            .collect();
+        black_box(estimates)
+    });
+}
+
+#[bench]
+fn bench_estimate_jaccard_index_many(b: &mut Bencher) {
+    let (query, others) = minhashes();
+
+    b.iter(|| {
+        // Inner closure, the actual test
+        black_box(query.estimate_jaccard_index_many(&others))
+    });
+}
+
+#[bench]
+fn bench_top_k_jaccard(b: &mut Bencher) {
+    let (query, others) = minhashes();
+
+    b.iter(|| {
+        // Inner closure, the actual test
+        black_box(query.top_k_jaccard(&others, 10))
+    });
+}
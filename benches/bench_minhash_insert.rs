@@ -59,3 +59,27 @@ fn bench_minhash_insert_with_keyed_fvn(b: &mut Bencher) {
         });
     });
 }
+
+#[bench]
+fn bench_minhash_insert_loop_with_siphashes13(b: &mut Bencher) {
+    let values: Vec<usize> = (0..100_000).collect();
+    let mut minhash: MinHash<u64, 128> = MinHash::new();
+
+    b.iter(|| {
+        // Inner closure, the actual test
+        for value in black_box(&values).iter() {
+            minhash.insert_with_siphashes13(value);
+        }
+    });
+}
+
+#[bench]
+fn bench_minhash_insert_slice_with_siphashes13(b: &mut Bencher) {
+    let values: Vec<usize> = (0..100_000).collect();
+    let mut minhash: MinHash<u64, 128> = MinHash::new();
+
+    b.iter(|| {
+        // Inner closure, the actual test
+        minhash.insert_slice_with_siphashes13(black_box(&values));
+    });
+}
@@ -0,0 +1,40 @@
+#![feature(test)]
+extern crate test;
+
+use minhash_rs::prelude::*;
+
+use test::{black_box, Bencher};
+
+fn bench_estimate_jaccard_index<Word, const PERMUTATIONS: usize>(b: &mut Bencher)
+where
+    Word: Min + XorShift + Copy + Eq + Maximal,
+    u64: Primitive<Word>,
+{
+    let first: MinHash<Word, PERMUTATIONS> = (0..1000_u64).collect();
+    let second: MinHash<Word, PERMUTATIONS> = (500..1500_u64).collect();
+
+    b.iter(|| {
+        // Inner closure, the actual test
+        black_box(black_box(&first).estimate_jaccard_index(black_box(&second)))
+    });
+}
+
+#[bench]
+fn bench_minhash_jaccard_u8_128(b: &mut Bencher) {
+    bench_estimate_jaccard_index::<u8, 128>(b);
+}
+
+#[bench]
+fn bench_minhash_jaccard_u8_8192(b: &mut Bencher) {
+    bench_estimate_jaccard_index::<u8, 8192>(b);
+}
+
+#[bench]
+fn bench_minhash_jaccard_u64_128(b: &mut Bencher) {
+    bench_estimate_jaccard_index::<u64, 128>(b);
+}
+
+#[bench]
+fn bench_minhash_jaccard_u64_8192(b: &mut Bencher) {
+    bench_estimate_jaccard_index::<u64, 8192>(b);
+}
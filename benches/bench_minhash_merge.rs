@@ -0,0 +1,42 @@
+#![feature(test)]
+extern crate test;
+
+use minhash_rs::prelude::*;
+
+use test::{black_box, Bencher};
+
+fn bench_merge<Word, const PERMUTATIONS: usize>(b: &mut Bencher)
+where
+    Word: Min + XorShift + Copy + Eq + Maximal,
+    u64: Primitive<Word>,
+{
+    let first: MinHash<Word, PERMUTATIONS> = (0..1000_u64).collect();
+    let second: MinHash<Word, PERMUTATIONS> = (500..1500_u64).collect();
+
+    b.iter(|| {
+        // Inner closure, the actual test
+        let mut union = *black_box(&first);
+        union |= black_box(&second);
+        black_box(union)
+    });
+}
+
+#[bench]
+fn bench_minhash_merge_u8_128(b: &mut Bencher) {
+    bench_merge::<u8, 128>(b);
+}
+
+#[bench]
+fn bench_minhash_merge_u8_8192(b: &mut Bencher) {
+    bench_merge::<u8, 8192>(b);
+}
+
+#[bench]
+fn bench_minhash_merge_u64_128(b: &mut Bencher) {
+    bench_merge::<u64, 128>(b);
+}
+
+#[bench]
+fn bench_minhash_merge_u64_8192(b: &mut Bencher) {
+    bench_merge::<u64, 8192>(b);
+}
@@ -0,0 +1,32 @@
+#![feature(test)]
+extern crate test;
+
+use minhash_rs::prelude::*;
+
+use test::{black_box, Bencher};
+
+const NUMBER_OF_ELEMENTS: usize = 100_000;
+
+#[bench]
+fn bench_minhash_insert_with_siphashes13(b: &mut Bencher) {
+    let mut minhash: MinHash<u64, 128> = MinHash::new();
+
+    b.iter(|| {
+        // Inner closure, the actual test
+        for i in 0..NUMBER_OF_ELEMENTS {
+            minhash.insert_with_siphashes13(black_box(i));
+        }
+    });
+}
+
+#[bench]
+fn bench_one_permutation_minhash_insert(b: &mut Bencher) {
+    let mut minhash: OnePermutationMinHash<u64, 128> = OnePermutationMinHash::new();
+
+    b.iter(|| {
+        // Inner closure, the actual test
+        for i in 0..NUMBER_OF_ELEMENTS {
+            minhash.insert(black_box(i));
+        }
+    });
+}
@@ -0,0 +1,43 @@
+#![feature(test)]
+extern crate test;
+
+use minhash_rs::prelude::*;
+
+use test::{black_box, Bencher};
+
+const NUMBER_OF_MINHASHES: u64 = 100;
+
+fn minhashes() -> Vec<MinHash<u64, 128>> {
+    (0..NUMBER_OF_MINHASHES)
+        .map(|i| (i * 100..i * 100 + 1000).collect())
+        .collect()
+}
+
+#[bench]
+fn bench_all_pairs_estimate_jaccard_index(b: &mut Bencher) {
+    let minhashes = minhashes();
+
+    b.iter(|| {
+        // Inner closure, the actual test
+        for first in minhashes.iter() {
+            for second in minhashes.iter() {
+                black_box(first.estimate_jaccard_index(second));
+            }
+        }
+    });
+}
+
+#[bench]
+fn bench_all_pairs_prepared_estimate_jaccard_index(b: &mut Bencher) {
+    let minhashes = minhashes();
+
+    b.iter(|| {
+        // Inner closure, the actual test
+        for first in minhashes.iter() {
+            let prepared = first.prepare();
+            for second in minhashes.iter() {
+                black_box(prepared.estimate_jaccard_index(second));
+            }
+        }
+    });
+}
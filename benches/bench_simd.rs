@@ -0,0 +1,24 @@
+#![feature(test)]
+extern crate test;
+
+use minhash_rs::prelude::*;
+
+use test::{black_box, Bencher};
+
+fn minhashes() -> (MinHash<u8, 8192>, MinHash<u8, 8192>) {
+    ((0..10_000_u64).collect(), (5_000..15_000_u64).collect())
+}
+
+#[bench]
+fn bench_estimate_jaccard_index_u8(b: &mut Bencher) {
+    let (first, second) = minhashes();
+
+    b.iter(|| black_box(&first).estimate_jaccard_index(black_box(&second)));
+}
+
+#[bench]
+fn bench_estimate_jaccard_index_simd_u8(b: &mut Bencher) {
+    let (first, second) = minhashes();
+
+    b.iter(|| black_box(&first).estimate_jaccard_index_simd(black_box(&second)));
+}
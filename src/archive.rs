@@ -0,0 +1,84 @@
+//! Module providing the zero-copy access to MinHash and MinHashArray archived with rkyv.
+//!
+//! # Implementative details
+//! Both the MinHash and the MinHashArray, as well as their archived counterparts,
+//! are transparent wrappers around arrays. When the words archive as themselves, as is
+//! the case for `u8`, `u16`, `u32`, `u64` and `u128` unless the `archive_le` or `archive_be`
+//! features of rkyv are enabled, the archived types have the same layout as the live ones,
+//! and can therefore be reinterpreted as references to them without copying any word.
+//! Note that `usize` archives as a 32-bit integer, and is therefore not supported.
+//!
+//! # Examples
+//! We can archive a MinHashArray and estimate the Jaccard index directly on the archive:
+//!
+//! ```
+//! use minhash_rs::prelude::*;
+//!
+//! let mut array = MinHashArray::<u64, 128, 3>::new();
+//! for i in 0..1000_u64 {
+//!     array[0].insert_with_siphashes13(i);
+//!     array[1].insert_with_siphashes13(i + 500);
+//! }
+//!
+//! let bytes = rkyv::to_bytes::<_, 4096>(&array).unwrap();
+//! let archived = unsafe { rkyv::archived_root::<MinHashArray<u64, 128, 3>>(&bytes) };
+//!
+//! assert_eq!(archived.as_minhash_array(), &array);
+//! assert_eq!(
+//!     archived.get(0).estimate_jaccard_index(archived.get(1)),
+//!     array[0].estimate_jaccard_index(&array[1])
+//! );
+//! ```
+
+use rkyv::Archive;
+
+use crate::prelude::{ArchivedMinHash, ArchivedMinHashArray, Maximal, MinHash, MinHashArray};
+
+impl<Word: Archive<Archived = Word>, const PERMUTATIONS: usize>
+    ArchivedMinHash<Word, PERMUTATIONS>
+{
+    /// Returns the archived MinHash as a reference to a MinHash, without copying it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash: MinHash<u32, 64> = (0..1000_u64).collect();
+    ///
+    /// let bytes = rkyv::to_bytes::<_, 256>(&minhash).unwrap();
+    /// let archived = unsafe { rkyv::archived_root::<MinHash<u32, 64>>(&bytes) };
+    ///
+    /// assert_eq!(archived.as_minhash(), &minhash);
+    /// ```
+    pub fn as_minhash(&self) -> &MinHash<Word, PERMUTATIONS> {
+        // SAFETY: both types are transparent wrappers around an array of `PERMUTATIONS`
+        // words, as the archived words are the words themselves.
+        unsafe { &*(self as *const Self as *const MinHash<Word, PERMUTATIONS>) }
+    }
+}
+
+impl<Word: Archive<Archived = Word>, const PERMUTATIONS: usize, const N: usize>
+    ArchivedMinHashArray<Word, PERMUTATIONS, N>
+{
+    /// Returns the archived MinHashArray as a reference to a MinHashArray, without copying it.
+    pub fn as_minhash_array(&self) -> &MinHashArray<Word, PERMUTATIONS, N> {
+        // SAFETY: both types are transparent wrappers around an array of `N` MinHashes,
+        // which in turn have the same layout as their archived counterparts.
+        unsafe { &*(self as *const Self as *const MinHashArray<Word, PERMUTATIONS, N>) }
+    }
+
+    /// Returns the MinHash at the provided index, without copying it.
+    ///
+    /// # Arguments
+    /// * `index` - The index of the MinHash.
+    ///
+    /// # Panics
+    /// If the index is out of bounds.
+    pub fn get(&self, index: usize) -> &MinHash<Word, PERMUTATIONS>
+    where
+        Word: Maximal,
+    {
+        &self.as_minhash_array()[index]
+    }
+}
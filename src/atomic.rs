@@ -1,8 +1,14 @@
 use core::hash::{Hash, Hasher};
-use core::{
-    mem::transmute,
-    sync::atomic::{AtomicU16, AtomicU32, AtomicU64, AtomicU8, AtomicUsize},
-};
+#[cfg(target_has_atomic = "16")]
+use core::sync::atomic::AtomicU16;
+#[cfg(target_has_atomic = "32")]
+use core::sync::atomic::AtomicU32;
+#[cfg(target_has_atomic = "64")]
+use core::sync::atomic::AtomicU64;
+#[cfg(target_has_atomic = "8")]
+use core::sync::atomic::AtomicU8;
+#[cfg(target_has_atomic = "ptr")]
+use core::sync::atomic::AtomicUsize;
 
 use fnv::FnvHasher;
 use siphasher::sip128::SipHasher13;
@@ -19,46 +25,126 @@ pub trait AtomicFetchMin {
     /// * `ordering` - The ordering to use.
     ///
     fn set_min(&self, value: Self::Word, ordering: core::sync::atomic::Ordering);
+
+    /// Create a new atomic holding the provided word.
+    ///
+    /// # Arguments
+    /// * `word` - The initial word.
+    fn from_word(word: Self::Word) -> Self;
+
+    /// Load the word held by the atomic.
+    ///
+    /// # Arguments
+    /// * `ordering` - The ordering to use.
+    fn load_word(&self, ordering: core::sync::atomic::Ordering) -> Self::Word;
+
+    /// Consume the atomic, returning the word it holds.
+    fn into_word(self) -> Self::Word;
 }
 
+#[cfg(target_has_atomic = "8")]
 impl AtomicFetchMin for AtomicU8 {
     type Word = u8;
 
     fn set_min(&self, value: Self::Word, ordering: core::sync::atomic::Ordering) {
         self.fetch_min(value, ordering);
     }
+
+    fn from_word(word: Self::Word) -> Self {
+        Self::new(word)
+    }
+
+    fn load_word(&self, ordering: core::sync::atomic::Ordering) -> Self::Word {
+        self.load(ordering)
+    }
+
+    fn into_word(self) -> Self::Word {
+        self.into_inner()
+    }
 }
 
+#[cfg(target_has_atomic = "16")]
 impl AtomicFetchMin for AtomicU16 {
     type Word = u16;
 
     fn set_min(&self, value: Self::Word, ordering: core::sync::atomic::Ordering) {
         self.fetch_min(value, ordering);
     }
+
+    fn from_word(word: Self::Word) -> Self {
+        Self::new(word)
+    }
+
+    fn load_word(&self, ordering: core::sync::atomic::Ordering) -> Self::Word {
+        self.load(ordering)
+    }
+
+    fn into_word(self) -> Self::Word {
+        self.into_inner()
+    }
 }
 
+#[cfg(target_has_atomic = "32")]
 impl AtomicFetchMin for AtomicU32 {
     type Word = u32;
 
     fn set_min(&self, value: Self::Word, ordering: core::sync::atomic::Ordering) {
         self.fetch_min(value, ordering);
     }
+
+    fn from_word(word: Self::Word) -> Self {
+        Self::new(word)
+    }
+
+    fn load_word(&self, ordering: core::sync::atomic::Ordering) -> Self::Word {
+        self.load(ordering)
+    }
+
+    fn into_word(self) -> Self::Word {
+        self.into_inner()
+    }
 }
 
+#[cfg(target_has_atomic = "64")]
 impl AtomicFetchMin for AtomicU64 {
     type Word = u64;
 
     fn set_min(&self, value: Self::Word, ordering: core::sync::atomic::Ordering) {
         self.fetch_min(value, ordering);
     }
+
+    fn from_word(word: Self::Word) -> Self {
+        Self::new(word)
+    }
+
+    fn load_word(&self, ordering: core::sync::atomic::Ordering) -> Self::Word {
+        self.load(ordering)
+    }
+
+    fn into_word(self) -> Self::Word {
+        self.into_inner()
+    }
 }
 
+#[cfg(target_has_atomic = "ptr")]
 impl AtomicFetchMin for AtomicUsize {
     type Word = usize;
 
     fn set_min(&self, value: Self::Word, ordering: core::sync::atomic::Ordering) {
         self.fetch_min(value, ordering);
     }
+
+    fn from_word(word: Self::Word) -> Self {
+        Self::new(word)
+    }
+
+    fn load_word(&self, ordering: core::sync::atomic::Ordering) -> Self::Word {
+        self.load(ordering)
+    }
+
+    fn into_word(self) -> Self::Word {
+        self.into_inner()
+    }
 }
 
 pub trait IterHashes<Word, const PERMUTATIONS: usize>
@@ -70,19 +156,8 @@ where
     ///
     /// # Arguments
     /// * `value` - The value to hash.
-    fn iter_hashes_from_value<H: Hash, HS: Hasher>(
-        value: H,
-        mut hasher: HS,
-    ) -> impl Iterator<Item = Word> {
-        // Calculate the hash.
-        value.hash(&mut hasher);
-        let mut hash: Word = hasher.finish().splitmix().splitmix().convert();
-
-        // Iterate over the words.
-        (0..PERMUTATIONS).map(move |_| {
-            hash = hash.xorshift();
-            hash
-        })
+    fn iter_hashes_from_value<H: Hash, HS: Hasher>(value: H, hasher: HS) -> HashIter<Word> {
+        HashIter::new(value, hasher, PERMUTATIONS)
     }
 
     /// Iterate on the SipHasher13 hashes from the provided value.
@@ -104,7 +179,7 @@ where
     /// assert!(minhash.may_contain_value_with_siphashes13(47));
     /// ```
     ///  
-    fn iter_siphashes13_from_value<H: Hash>(value: H) -> impl Iterator<Item = Word> {
+    fn iter_siphashes13_from_value<H: Hash>(value: H) -> HashIter<Word> {
         Self::iter_hashes_from_value(value, SipHasher13::new())
     }
 
@@ -135,7 +210,7 @@ where
         value: H,
         key0: u64,
         key1: u64,
-    ) -> impl Iterator<Item = Word> {
+    ) -> HashIter<Word> {
         Self::iter_hashes_from_value(value, SipHasher13::new_with_keys(key0, key1))
     }
 
@@ -158,10 +233,19 @@ where
     /// assert!(minhash.may_contain_value_with_fvn(47));
     /// ```
     ///  
-    fn iter_fvn_from_value<H: Hash>(value: H) -> impl Iterator<Item = Word> {
+    fn iter_fvn_from_value<H: Hash>(value: H) -> HashIter<Word> {
         Self::iter_hashes_from_value(value, FnvHasher::default())
     }
 
+    /// Iterate on the XxHash64 hashes from the provided value.
+    ///
+    /// # Arguments
+    /// * `value` - The value to hash.
+    #[cfg(feature = "xxhash")]
+    fn iter_xxhash64_from_value<H: Hash>(value: H) -> HashIter<Word> {
+        Self::iter_hashes_from_value(value, twox_hash::XxHash64::with_seed(0))
+    }
+
     /// Iterate on the keyed SipHasher13 hashes from the provided value.
     ///
     /// # Arguments
@@ -178,7 +262,7 @@ where
     ///
     /// assert!(!minhash.may_contain_value_with_keyed_fvn(42, key));
     ///
-    fn iter_keyed_fvn_from_value<H: Hash>(value: H, key: u64) -> impl Iterator<Item = Word> {
+    fn iter_keyed_fvn_from_value<H: Hash>(value: H, key: u64) -> HashIter<Word> {
         Self::iter_hashes_from_value(value, FnvHasher::with_key(key))
     }
 }
@@ -197,7 +281,14 @@ where
     u64: Primitive<<AtomicWord as AtomicFetchMin>::Word>,
     AtomicWord::Word: XorShift + Copy,
 {
-    /// Iterate over the words.
+    /// Iterate over the atomic words.
+    ///
+    /// # Implementative details
+    /// The atomic insertions write through a shared reference, which is only sound
+    /// when the words are themselves atomics owned by the implementor, as in the
+    /// [`ConcurrentMinHash`]. The words of a [`MinHash`] are plain integers and
+    /// must never be reinterpreted as atomics: matching size and alignment do not
+    /// make the memory behind a shared reference mutable.
     fn iter_atomic<'a>(&'a self) -> impl Iterator<Item = &'a AtomicWord>
     where
         AtomicWord: 'a,
@@ -207,30 +298,42 @@ where
     ///
     /// # Arguments
     /// * `value` - The value to insert.
+    /// * `ordering` - The ordering to use.
     ///
     /// # Examples
     ///
     /// ```
+    /// use core::sync::atomic::{AtomicU64, Ordering};
     /// use minhash_rs::prelude::*;
     ///
-    /// let mut minhash = MinHash::<u64, 4>::new();
+    /// let minhash = ConcurrentMinHash::<AtomicU64, 4>::new();
+    ///
+    /// minhash.fetch_insert_with_siphashes13(42, Ordering::Relaxed);
+    /// minhash.fetch_insert_with_siphashes13(47, Ordering::Relaxed);
+    ///
+    /// let minhash = minhash.into_minhash();
     ///
-    /// assert!(!minhash.may_contain_value_with_siphashes13(42));
-    /// minhash.fetch_insert_with_siphashes13(42, core::sync::atomic::Ordering::Relaxed);
     /// assert!(!minhash.is_empty());
-    /// assert!(
-    ///     minhash.may_contain_value_with_siphashes13(42),
-    ///     concat!(
-    ///         "The MinHash should contain the value 42, ",
-    ///         "but it does not. The MinHash is: {:?}. ",
-    ///         "The hashes associated to the value 42 are: {:?}."
-    ///     ),
-    ///     minhash,
-    ///     MinHash::<u64, 4>::iter_siphashes13_from_value(42).collect::<Vec<_>>()
-    /// );
-    /// minhash.fetch_insert_with_siphashes13(47, core::sync::atomic::Ordering::Relaxed);
+    /// assert!(minhash.may_contain_value_with_siphashes13(42));
     /// assert!(minhash.may_contain_value_with_siphashes13(47));
+    /// ```
     ///
+    /// The pointer-sized words are supported as well, and match the non-atomic insertion:
+    ///
+    /// ```
+    /// use core::sync::atomic::{AtomicUsize, Ordering};
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let atomic = ConcurrentMinHash::<AtomicUsize, 64>::new();
+    /// let mut minhash = MinHash::<usize, 64>::new();
+    ///
+    /// for value in 0..100_u64 {
+    ///     atomic.fetch_insert_with_siphashes13(value, Ordering::Relaxed);
+    ///     minhash.insert_with_siphashes13(value);
+    /// }
+    ///
+    /// assert_eq!(atomic.into_minhash(), minhash);
+    /// assert!((minhash.estimate_cardinality() - 100.0).abs() < 30.0);
     /// ```
     ///
     fn fetch_insert_with_siphashes13<H: Hash>(
@@ -247,38 +350,100 @@ where
         }
     }
 
+    /// Insert a value into the MinHash atomically, with SipHasher13 and relaxed ordering.
+    ///
+    /// # Arguments
+    /// * `value` - The value to insert.
+    ///
+    /// # Implementative details
+    /// The relaxed ordering is sufficient to build a MinHash concurrently: the minimum
+    /// is commutative, associative and idempotent, so that the words converge to the
+    /// same values whatever the order in which the insertions are applied. Once all the
+    /// inserting threads have been joined, the MinHash is identical to the sequential one,
+    /// since joining a thread synchronizes with all of its writes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::sync::atomic::AtomicU64;
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash = ConcurrentMinHash::<AtomicU64, 128>::new();
+    ///
+    /// std::thread::scope(|scope| {
+    ///     for thread in 0..4_u64 {
+    ///         let minhash = &minhash;
+    ///         scope.spawn(move || {
+    ///             for value in thread * 250..(thread + 1) * 250 {
+    ///                 minhash.fetch_insert_with_siphashes13_relaxed(value);
+    ///             }
+    ///         });
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(minhash.into_minhash(), (0..1000_u64).collect());
+    /// ```
+    fn fetch_insert_with_siphashes13_relaxed<H: Hash>(&self, value: H) {
+        self.fetch_insert_with_siphashes13(value, core::sync::atomic::Ordering::Relaxed);
+    }
+
+    /// Insert a value into the MinHash atomically, with SipHasher13 and sequentially consistent ordering.
+    ///
+    /// # Arguments
+    /// * `value` - The value to insert.
+    ///
+    /// # Implementative details
+    /// The sequentially consistent ordering is only required when the MinHash is read
+    /// while other threads are still inserting, and the reads must be ordered with
+    /// respect to other atomic operations of the program, for instance to observe
+    /// an insertion after a flag signalling it was set. When the MinHash is only read
+    /// after joining the inserting threads, prefer the cheaper
+    /// [`AtomicMinHash::fetch_insert_with_siphashes13_relaxed`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::sync::atomic::{AtomicU32, Ordering};
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash = ConcurrentMinHash::<AtomicU32, 128>::new();
+    ///
+    /// minhash.fetch_insert_with_siphashes13_seqcst(42);
+    ///
+    /// assert!(minhash
+    ///     .load(Ordering::SeqCst)
+    ///     .may_contain_value_with_siphashes13(42));
+    /// ```
+    fn fetch_insert_with_siphashes13_seqcst<H: Hash>(&self, value: H) {
+        self.fetch_insert_with_siphashes13(value, core::sync::atomic::Ordering::SeqCst);
+    }
+
     /// Insert a value into the MinHash atomically, with keyed SipHasher13.
     ///
     /// # Arguments
     /// * `value` - The value to insert.
     /// * `key0` - The first key.
     /// * `key1` - The second key.
+    /// * `ordering` - The ordering to use.
     ///
     /// # Examples
     ///
     /// ```
+    /// use core::sync::atomic::{AtomicU64, Ordering};
     /// use minhash_rs::prelude::*;
     ///
-    /// let mut minhash = MinHash::<u64, 4>::new();
+    /// let minhash = ConcurrentMinHash::<AtomicU64, 4>::new();
     /// let key0 = 0x0123456789ABCDEF;
     /// let key1 = 0xFEDCBA9876543210;
     ///
-    /// assert!(!minhash.may_contain_value_with_keyed_siphashes13(42, key0, key1));
-    /// minhash.fetch_insert_with_keyed_siphashes13(42, key0, key1, core::sync::atomic::Ordering::Relaxed);
+    /// minhash.fetch_insert_with_keyed_siphashes13(42, key0, key1, Ordering::Relaxed);
+    /// minhash.fetch_insert_with_keyed_siphashes13(47, key0, key1, Ordering::Relaxed);
+    ///
+    /// let minhash = minhash.into_minhash();
+    ///
     /// assert!(!minhash.is_empty());
-    /// assert!(
-    ///     minhash.may_contain_value_with_keyed_siphashes13(42, key0, key1),
-    ///     concat!(
-    ///         "The MinHash should contain the value 42, ",
-    ///         "but it does not. The MinHash is: {:?}. ",
-    ///         "The hashes associated to the value 42 are: {:?}."
-    ///     ),
-    ///     minhash,
-    ///     MinHash::<u64, 4>::iter_keyed_siphashes13_from_value(42, key0, key1).collect::<Vec<_>>()
-    /// );
-    /// minhash.fetch_insert_with_keyed_siphashes13(47, key0, key1, core::sync::atomic::Ordering::Relaxed);
+    /// assert!(minhash.may_contain_value_with_keyed_siphashes13(42, key0, key1));
     /// assert!(minhash.may_contain_value_with_keyed_siphashes13(47, key0, key1));
-    ///
     /// ```
     ///
     fn fetch_insert_with_keyed_siphashes13<H: Hash>(
@@ -296,69 +461,76 @@ where
             word.set_min(hash, ordering);
         }
     }
-}
-
-impl<const PERMUTATIONS: usize> AtomicMinHash<AtomicU8, PERMUTATIONS>
-    for MinHash<u8, PERMUTATIONS>
-{
-    /// Iterate over the words.
-    fn iter_atomic<'a>(&'a self) -> impl Iterator<Item = &'a AtomicU8>
-    where
-        Self: 'a,
-    {
-        let words: &[AtomicU8] = unsafe { transmute(self.as_ref()) };
-        words.iter()
-    }
-}
 
-impl<const PERMUTATIONS: usize> AtomicMinHash<AtomicU16, PERMUTATIONS>
-    for MinHash<u16, PERMUTATIONS>
-{
-    /// Iterate over the words.
-    fn iter_atomic<'a>(&'a self) -> impl Iterator<Item = &'a AtomicU16>
-    where
-        Self: 'a,
-    {
-        let words: &[AtomicU16] = unsafe { transmute(self.as_ref()) };
-        words.iter()
-    }
-}
-
-impl<const PERMUTATIONS: usize> AtomicMinHash<AtomicU32, PERMUTATIONS>
-    for MinHash<u32, PERMUTATIONS>
-{
-    /// Iterate over the words.
-    fn iter_atomic<'a>(&'a self) -> impl Iterator<Item = &'a AtomicU32>
-    where
-        Self: 'a,
-    {
-        let words: &[AtomicU32] = unsafe { transmute(self.as_ref()) };
-        words.iter()
-    }
-}
-
-impl<const PERMUTATIONS: usize> AtomicMinHash<AtomicU64, PERMUTATIONS>
-    for MinHash<u64, PERMUTATIONS>
-{
-    /// Iterate over the words.
-    fn iter_atomic<'a>(&'a self) -> impl Iterator<Item = &'a AtomicU64>
-    where
-        Self: 'a,
-    {
-        let words: &[AtomicU64] = unsafe { transmute(self.as_ref()) };
-        words.iter()
+    /// Insert a value into the MinHash atomically, with FNV.
+    ///
+    /// # Arguments
+    /// * `value` - The value to insert.
+    /// * `ordering` - The ordering to use.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::sync::atomic::{AtomicU64, Ordering};
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash = ConcurrentMinHash::<AtomicU64, 4>::new();
+    ///
+    /// minhash.fetch_insert_with_fvn(42, Ordering::Relaxed);
+    /// minhash.fetch_insert_with_fvn(47, Ordering::Relaxed);
+    ///
+    /// let minhash = minhash.into_minhash();
+    ///
+    /// assert!(!minhash.is_empty());
+    /// assert!(minhash.may_contain_value_with_fvn(42));
+    /// assert!(minhash.may_contain_value_with_fvn(47));
+    /// ```
+    ///
+    fn fetch_insert_with_fvn<H: Hash>(&self, value: H, ordering: core::sync::atomic::Ordering) {
+        // Iterate over the words.
+        for (word, hash) in self.iter_atomic().zip(Self::iter_fvn_from_value(value)) {
+            word.set_min(hash, ordering);
+        }
     }
-}
 
-impl<const PERMUTATIONS: usize> AtomicMinHash<AtomicUsize, PERMUTATIONS>
-    for MinHash<usize, PERMUTATIONS>
-{
-    /// Iterate over the words.
-    fn iter_atomic<'a>(&'a self) -> impl Iterator<Item = &'a AtomicUsize>
-    where
-        Self: 'a,
-    {
-        let words: &[AtomicUsize] = unsafe { transmute(self.as_ref()) };
-        words.iter()
+    /// Insert a value into the MinHash atomically, with keyed FNV.
+    ///
+    /// # Arguments
+    /// * `value` - The value to insert.
+    /// * `key` - The key.
+    /// * `ordering` - The ordering to use.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::sync::atomic::{AtomicU64, Ordering};
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash = ConcurrentMinHash::<AtomicU64, 4>::new();
+    /// let key = 0x0123456789ABCDEF;
+    ///
+    /// minhash.fetch_insert_with_keyed_fvn(42, key, Ordering::Relaxed);
+    /// minhash.fetch_insert_with_keyed_fvn(47, key, Ordering::Relaxed);
+    ///
+    /// let minhash = minhash.into_minhash();
+    ///
+    /// assert!(!minhash.is_empty());
+    /// assert!(minhash.may_contain_value_with_keyed_fvn(42, key));
+    /// assert!(minhash.may_contain_value_with_keyed_fvn(47, key));
+    /// ```
+    ///
+    fn fetch_insert_with_keyed_fvn<H: Hash>(
+        &self,
+        value: H,
+        key: u64,
+        ordering: core::sync::atomic::Ordering,
+    ) {
+        // Iterate over the words.
+        for (word, hash) in self
+            .iter_atomic()
+            .zip(Self::iter_keyed_fvn_from_value(value, key))
+        {
+            word.set_min(hash, ordering);
+        }
     }
 }
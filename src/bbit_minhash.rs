@@ -0,0 +1,147 @@
+//! Module providing the b-bit MinHash data structure.
+//!
+//! # What is a b-bit MinHash?
+//! A b-bit MinHash only keeps the lowest `B` bits of each of the words of a
+//! MinHash, packed into a bitset, reducing the memory requirements by a factor
+//! of `Word::BITS / B`. Two different minima share their lowest `B` bits with
+//! probability `2^-B`, which biases the fraction of matching positions upwards:
+//! the Jaccard index is therefore estimated as `(observed - 2^-B) / (1 - 2^-B)`.
+//! The variance of the estimate grows as `B` decreases, so that fewer bits
+//! require more permutations to achieve the same precision.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::prelude::{MinHash, Primitive};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BBitMinHash<const PERMUTATIONS: usize, const B: usize> {
+    bits: Vec<u64>,
+}
+
+impl<const PERMUTATIONS: usize, const B: usize> BBitMinHash<PERMUTATIONS, B> {
+    /// The number of values packed in each 64-bit word.
+    const VALUES_PER_WORD: usize = {
+        assert!(
+            B == 1 || B == 2 || B == 4,
+            "The number of bits must be either 1, 2 or 4."
+        );
+        64 / B
+    };
+
+    /// The number of 64-bit words required to pack all the values.
+    const NUMBER_OF_WORDS: usize = PERMUTATIONS.div_ceil(Self::VALUES_PER_WORD);
+
+    /// The mask with the lowest bit of each packed value set.
+    const LOW_BITS: u64 = u64::MAX / ((1 << B) - 1);
+
+    /// Returns the number of permutations.
+    pub fn number_of_permutations(&self) -> usize {
+        PERMUTATIONS
+    }
+
+    /// Returns memory required to store the b-bit MinHash in bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash: MinHash<u64, 128> = (0..1000_u64).collect();
+    ///
+    /// assert_eq!(BBitMinHash::<128, 1>::from(&minhash).memory(), 128);
+    /// assert_eq!(BBitMinHash::<128, 4>::from(&minhash).memory(), 128 * 4);
+    /// ```
+    pub fn memory(&self) -> usize {
+        self.bits.len() * 64
+    }
+
+    /// Calculate the similarity between two b-bit MinHashes.
+    ///
+    /// # Arguments
+    /// * `other` - The other b-bit MinHash to compare to.
+    ///
+    /// # Implementative details
+    /// The estimate is corrected for the matches caused by the collisions of the
+    /// lowest `B` bits, so that it is unbiased, but it may be slightly negative
+    /// for nearly disjoint sets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let first: MinHash<u64, 1024> = (0..1000_u64).collect();
+    /// let second: MinHash<u64, 1024> = (500..1500_u64).collect();
+    ///
+    /// let first_bbit = BBitMinHash::<1024, 2>::from(&first);
+    /// let second_bbit = BBitMinHash::<1024, 2>::from(&second);
+    ///
+    /// let estimate = first_bbit.estimate_jaccard_index(&second_bbit);
+    ///
+    /// assert!((estimate - 1.0 / 3.0).abs() < 0.1, "Estimate: {}", estimate);
+    /// assert_eq!(first_bbit.estimate_jaccard_index(&first_bbit), 1.0);
+    /// ```
+    pub fn estimate_jaccard_index(&self, other: &Self) -> f64 {
+        let mut mismatches = 0;
+        for (left, right) in self.bits.iter().zip(other.bits.iter()) {
+            // We collapse each of the packed values of the XOR onto its lowest bit,
+            // which is set if and only if the packed values differ.
+            let mut difference = left ^ right;
+            let mut shift = 1;
+            while shift < B {
+                difference |= difference >> shift;
+                shift *= 2;
+            }
+            mismatches += (difference & Self::LOW_BITS).count_ones() as usize;
+        }
+        let observed = (PERMUTATIONS - mismatches) as f64 / PERMUTATIONS as f64;
+        let collision = 1.0 / (1 << B) as f64;
+        (observed - collision) / (1.0 - collision)
+    }
+}
+
+impl<Word: Copy + Primitive<u64>, const PERMUTATIONS: usize, const B: usize>
+    From<&MinHash<Word, PERMUTATIONS>> for BBitMinHash<PERMUTATIONS, B>
+{
+    /// Create a new b-bit MinHash from the lowest `B` bits of the words of the provided MinHash.
+    ///
+    /// Words wider than 64 bits are first converted into their highest 64 bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash: MinHash<u32, 128> = (0..1000_u64).collect();
+    /// let bbit = BBitMinHash::<128, 4>::from(&minhash);
+    ///
+    /// assert_eq!(bbit.number_of_permutations(), 128);
+    /// ```
+    ///
+    /// Numbers of bits other than 1, 2 and 4 do not compile:
+    ///
+    /// ```compile_fail
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash: MinHash<u32, 128> = (0..1000_u64).collect();
+    /// let bbit = BBitMinHash::<128, 3>::from(&minhash);
+    /// ```
+    fn from(minhash: &MinHash<Word, PERMUTATIONS>) -> Self {
+        let mask = (1 << B) - 1;
+        let mut bits = vec![0; Self::NUMBER_OF_WORDS];
+        for (i, word) in minhash.iter().enumerate() {
+            let value = Primitive::<u64>::convert(*word) & mask;
+            bits[i / Self::VALUES_PER_WORD] |= value << ((i % Self::VALUES_PER_WORD) * B);
+        }
+        Self { bits }
+    }
+}
+
+impl<Word: Copy + Primitive<u64>, const PERMUTATIONS: usize, const B: usize>
+    From<MinHash<Word, PERMUTATIONS>> for BBitMinHash<PERMUTATIONS, B>
+{
+    fn from(minhash: MinHash<Word, PERMUTATIONS>) -> Self {
+        Self::from(&minhash)
+    }
+}
@@ -0,0 +1,131 @@
+//! Module providing a builder of MinHashes sharing the same keys.
+//!
+//! # Why should I use this?
+//! MinHashes built with different keys use different permutations, and
+//! comparing them yields meaningless estimates. The [`MinHashBuilder`] holds
+//! the keys of the keyed SipHasher13 of a whole workflow, so that they are
+//! configured once and all the MinHashes it builds are comparable.
+
+use core::hash::Hash;
+
+use crate::prelude::{Maximal, Min, MinHash, Primitive, XorShift};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct MinHashBuilder {
+    key0: u64,
+    key1: u64,
+}
+
+impl MinHashBuilder {
+    /// Create a new MinHashBuilder with the provided keys.
+    ///
+    /// # Arguments
+    /// * `key0` - The first key of the SipHasher13.
+    /// * `key1` - The second key of the SipHasher13.
+    ///
+    /// # Implementative details
+    /// The default builder uses the keys `(0, 0)`, which are the ones used by
+    /// [`MinHash::insert_with_siphashes13`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash: MinHash<u64, 128> = MinHashBuilder::default().from_iter(0..1000_u64);
+    ///
+    /// assert_eq!(minhash, (0..1000_u64).collect());
+    /// ```
+    pub fn new(key0: u64, key1: u64) -> Self {
+        Self { key0, key1 }
+    }
+
+    /// Returns the keys of the builder.
+    pub fn keys(&self) -> (u64, u64) {
+        (self.key0, self.key1)
+    }
+
+    /// Returns a new empty MinHash.
+    pub fn build<Word: Maximal, const PERMUTATIONS: usize>(&self) -> MinHash<Word, PERMUTATIONS> {
+        MinHash::new()
+    }
+
+    /// Insert a value into the provided MinHash, using the keys of the builder.
+    ///
+    /// # Arguments
+    /// * `minhash` - The MinHash to insert the value into.
+    /// * `value` - The value to insert.
+    pub fn insert<Word: Min + XorShift + Copy + Eq, const PERMUTATIONS: usize, H: Hash>(
+        &self,
+        minhash: &mut MinHash<Word, PERMUTATIONS>,
+        value: H,
+    ) where
+        u64: Primitive<Word>,
+    {
+        minhash.insert_with_keyed_siphashes13(value, self.key0, self.key1);
+    }
+
+    /// Returns whether the provided MinHash may contain the value, using the keys of the builder.
+    ///
+    /// # Arguments
+    /// * `minhash` - The MinHash to check.
+    /// * `value` - The value to check.
+    pub fn may_contain<Word: Min + XorShift + Copy + Eq, const PERMUTATIONS: usize, H: Hash>(
+        &self,
+        minhash: &MinHash<Word, PERMUTATIONS>,
+        value: H,
+    ) -> bool
+    where
+        u64: Primitive<Word>,
+    {
+        minhash.may_contain_value_with_keyed_siphashes13(value, self.key0, self.key1)
+    }
+
+    /// Returns the MinHash of the values of the provided iterator, using the keys of the builder.
+    ///
+    /// # Arguments
+    /// * `iter` - The values to insert.
+    ///
+    /// # Examples
+    /// MinHashes built with the same keys are comparable, while MinHashes built with different
+    /// keys use different permutations: comparing them does not estimate any Jaccard index.
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let builder = MinHashBuilder::new(0x0123456789ABCDEF, 0xFEDCBA9876543210);
+    /// let same_keys = MinHashBuilder::new(0x0123456789ABCDEF, 0xFEDCBA9876543210);
+    /// let other_keys = MinHashBuilder::new(42, 47);
+    ///
+    /// let minhash: MinHash<u64, 128> = builder.from_iter(0..1000_u64);
+    ///
+    /// assert_eq!(minhash, same_keys.from_iter(0..1000_u64));
+    /// assert_ne!(minhash, other_keys.from_iter(0..1000_u64));
+    ///
+    /// let mut inserted = builder.build::<u64, 128>();
+    /// for value in 0..1000_u64 {
+    ///     builder.insert(&mut inserted, value);
+    /// }
+    ///
+    /// assert_eq!(minhash, inserted);
+    /// assert!(builder.may_contain(&minhash, 42_u64));
+    /// ```
+    pub fn from_iter<
+        Word: Min + XorShift + Copy + Eq + Maximal,
+        const PERMUTATIONS: usize,
+        H: Hash,
+        I: IntoIterator<Item = H>,
+    >(
+        &self,
+        iter: I,
+    ) -> MinHash<Word, PERMUTATIONS>
+    where
+        u64: Primitive<Word>,
+    {
+        let mut minhash = self.build();
+        for value in iter {
+            self.insert(&mut minhash, value);
+        }
+        minhash
+    }
+}
@@ -0,0 +1,229 @@
+//! Module providing the cardinality estimators that can be used with a MinHash.
+//!
+//! # How does it work?
+//! Each word of a MinHash is the minimum of the hash values of the elements
+//! of the set under a given permutation. Once normalized into the unit interval,
+//! each word behaves as the minimum of `n` uniform random variables, where `n`
+//! is the number of distinct elements in the set, and therefore has expected
+//! value `1 / (n + 1)`. The estimators in this module invert this relationship
+//! in different ways, and can be selected with [`MinHash::estimate_cardinality_with`].
+//!
+//! All the estimators return `0.0` for an empty MinHash, i.e. when all the words
+//! are still equal to the maximal value, and [`f64::INFINITY`] when the estimator
+//! cannot bound the cardinality, as happens for a fully saturated MinHash.
+//!
+//! [`MinHash::estimate_cardinality_with`]: crate::minhash::MinHash::estimate_cardinality_with
+
+use crate::prelude::Primitive;
+
+/// Trait for the estimators of the number of distinct elements inserted into a MinHash.
+///
+/// The estimators are zero-sized types that only provide this associated function,
+/// and are selected as the type parameter of [`MinHash::estimate_cardinality_with`].
+///
+/// [`MinHash::estimate_cardinality_with`]: crate::minhash::MinHash::estimate_cardinality_with
+pub trait CardinalityEstimator<Word> {
+    /// Returns the estimated number of distinct elements that produced the provided words.
+    ///
+    /// # Arguments
+    /// * `words` - The words of the MinHash.
+    /// * `max` - The maximal value a word can assume.
+    fn estimate(words: &[Word], max: Word) -> f64;
+}
+
+/// Returns whether all the provided words are still equal to the maximal value.
+fn is_empty<Word: Eq>(words: &[Word], max: &Word) -> bool {
+    words.iter().all(|word| word == max)
+}
+
+/// Returns the sum of the words normalized by the maximal value.
+fn normalized_sum<Word: Primitive<f64> + Copy>(words: &[Word], max: Word) -> f64 {
+    let max: f64 = max.convert();
+    words
+        .iter()
+        .map(|word| Primitive::<f64>::convert(*word) / max)
+        .sum()
+}
+
+/// Method of moments estimator, `k / sum(u) - 1`.
+///
+/// # Examples
+///
+/// ```
+/// use minhash_rs::prelude::*;
+///
+/// let minhash: MinHash<u64, 1024> = (0..1000_u64).collect();
+/// let estimate = minhash.estimate_cardinality_with::<MeanEstimator>();
+///
+/// assert!((estimate - 1000.0).abs() < 100.0, "Estimate: {}", estimate);
+/// assert_eq!(MinHash::<u64, 1024>::new().estimate_cardinality_with::<MeanEstimator>(), 0.0);
+/// ```
+pub struct MeanEstimator;
+
+impl<Word: Primitive<f64> + Copy + Eq> CardinalityEstimator<Word> for MeanEstimator {
+    fn estimate(words: &[Word], max: Word) -> f64 {
+        if is_empty(words, &max) {
+            return 0.0;
+        }
+        let sum = normalized_sum(words, max);
+        if sum == 0.0 {
+            return f64::INFINITY;
+        }
+        words.len() as f64 / sum - 1.0
+    }
+}
+
+/// K-minimum values style estimator, `(k - 1) / sum(u)`.
+///
+/// # Implementative details
+/// When the normalized words are treated as exponential random variables
+/// of rate `n`, this is the unbiased estimator of `n`.
+///
+/// # Examples
+///
+/// ```
+/// use minhash_rs::prelude::*;
+///
+/// let minhash: MinHash<u64, 1024> = (0..1000_u64).collect();
+/// let estimate = minhash.estimate_cardinality_with::<KmvEstimator>();
+///
+/// assert!((estimate - 1000.0).abs() < 100.0, "Estimate: {}", estimate);
+/// assert_eq!(MinHash::<u64, 1024>::new().estimate_cardinality_with::<KmvEstimator>(), 0.0);
+/// ```
+pub struct KmvEstimator;
+
+impl<Word: Primitive<f64> + Copy + Eq> CardinalityEstimator<Word> for KmvEstimator {
+    fn estimate(words: &[Word], max: Word) -> f64 {
+        if is_empty(words, &max) {
+            return 0.0;
+        }
+        let sum = normalized_sum(words, max);
+        if sum == 0.0 {
+            return f64::INFINITY;
+        }
+        (words.len() as f64 - 1.0) / sum
+    }
+}
+
+/// Maximum likelihood estimator, `-k / sum(ln(1 - u))`.
+///
+/// # Implementative details
+/// Each normalized word follows a `Beta(1, n)` distribution, whose
+/// likelihood is maximized in closed form by this estimator. The words
+/// are normalized by `max + 1` so that the logarithm is always finite.
+///
+/// # Examples
+///
+/// ```
+/// use minhash_rs::prelude::*;
+///
+/// let minhash: MinHash<u64, 1024> = (0..1000_u64).collect();
+/// let estimate = minhash.estimate_cardinality_with::<MleEstimator>();
+///
+/// assert!((estimate - 1000.0).abs() < 100.0, "Estimate: {}", estimate);
+/// assert_eq!(MinHash::<u64, 1024>::new().estimate_cardinality_with::<MleEstimator>(), 0.0);
+/// ```
+pub struct MleEstimator;
+
+impl<Word: Primitive<f64> + Copy + Eq> CardinalityEstimator<Word> for MleEstimator {
+    fn estimate(words: &[Word], max: Word) -> f64 {
+        if is_empty(words, &max) {
+            return 0.0;
+        }
+        let space = Primitive::<f64>::convert(max) + 1.0;
+        let log_sum: f64 = words
+            .iter()
+            .map(|word| libm::log1p(-Primitive::<f64>::convert(*word) / space))
+            .sum();
+        if log_sum == 0.0 {
+            return f64::INFINITY;
+        }
+        -(words.len() as f64) / log_sum
+    }
+}
+
+/// Maximum likelihood estimator accounting for the finite number of values a word can assume.
+///
+/// # Implementative details
+/// Narrow words such as `u8` quantize the minima, so that many of them collapse
+/// to the same small values as the cardinality grows. This estimator maximizes
+/// the exact likelihood of the observed words over a space of `max + 1` values,
+/// where the probability of observing the word `w` is `a^n - b^n` with
+/// `a = 1 - w / (max + 1)` and `b = 1 - (w + 1) / (max + 1)`.
+/// The maximum is found by bisection over the derivative of the log-likelihood,
+/// which is monotonically decreasing in `n`. For wide words, the estimate
+/// converges to the one of the [`MleEstimator`].
+///
+/// # Examples
+///
+/// ```
+/// use minhash_rs::prelude::*;
+///
+/// let minhash: MinHash<u64, 1024> = (0..1000_u64).collect();
+/// let estimate = minhash.estimate_cardinality_with::<FiniteSpaceEstimator>();
+///
+/// assert!((estimate - 1000.0).abs() < 100.0, "Estimate: {}", estimate);
+///
+/// let minhash: MinHash<u16, 1024> = (0..1000_u64).collect();
+/// let estimate = minhash.estimate_cardinality_with::<FiniteSpaceEstimator>();
+///
+/// assert!((estimate - 1000.0).abs() < 100.0, "Estimate: {}", estimate);
+/// assert_eq!(MinHash::<u8, 1024>::new().estimate_cardinality_with::<FiniteSpaceEstimator>(), 0.0);
+/// assert_eq!(
+///     MinHash::<u8, 16>::from_words([0; 16]).estimate_cardinality_with::<FiniteSpaceEstimator>(),
+///     f64::INFINITY
+/// );
+/// ```
+pub struct FiniteSpaceEstimator;
+
+impl FiniteSpaceEstimator {
+    /// Returns the derivative of the log-likelihood of the cardinality `n`.
+    ///
+    /// # Arguments
+    /// * `terms` - Iterator over the pairs `(ln(a), ln(b / a))` of each word.
+    /// * `n` - The cardinality to evaluate the derivative at.
+    fn log_likelihood_derivative(terms: impl Iterator<Item = (f64, f64)>, n: f64) -> f64 {
+        terms
+            .map(|(log_a, log_ratio)| {
+                if log_ratio == f64::NEG_INFINITY {
+                    log_a
+                } else {
+                    log_a + libm::exp(n * log_ratio) * log_ratio / libm::expm1(n * log_ratio)
+                }
+            })
+            .sum()
+    }
+}
+
+impl<Word: Primitive<f64> + Copy + Eq> CardinalityEstimator<Word> for FiniteSpaceEstimator {
+    fn estimate(words: &[Word], max: Word) -> f64 {
+        if is_empty(words, &max) {
+            return 0.0;
+        }
+        let space = Primitive::<f64>::convert(max) + 1.0;
+        let terms = || {
+            words.iter().map(move |word| {
+                let word: f64 = Primitive::<f64>::convert(*word);
+                (
+                    libm::log1p(-word / space),
+                    libm::log1p(-1.0 / (space - word)),
+                )
+            })
+        };
+
+        let mut lower: f64 = 1e-6;
+        let mut upper: f64 = 1e30;
+        if Self::log_likelihood_derivative(terms(), upper) >= 0.0 {
+            return f64::INFINITY;
+        }
+        for _ in 0..128 {
+            let middle = libm::sqrt(lower * upper);
+            if Self::log_likelihood_derivative(terms(), middle) > 0.0 {
+                lower = middle;
+            } else {
+                upper = middle;
+            }
+        }
+        libm::sqrt(lower * upper)
+    }
+}
@@ -0,0 +1,304 @@
+//! Module providing a MinHash and a MinHashArray whose words are atomics, to insert values from several threads.
+//!
+//! # Why not insert atomically into a MinHash?
+//! The words of a [`MinHash`] are plain integers, which may not be written through
+//! a shared reference: reinterpreting them as atomics while the MinHash is shared
+//! is undefined behaviour, whatever the layout of the atomic types, and the
+//! optimizer does exploit it. The [`ConcurrentMinHash`] owns atomic words instead,
+//! so that the insertions of [`AtomicMinHash`] through a shared reference are sound.
+//! It is converted from and into a [`MinHash`] before and after the concurrent insertions.
+//!
+//! # Examples
+//!
+//! ```
+//! use core::sync::atomic::AtomicU64;
+//! use minhash_rs::prelude::*;
+//!
+//! let concurrent = ConcurrentMinHash::<AtomicU64, 128>::new();
+//!
+//! std::thread::scope(|scope| {
+//!     for thread in 0..4_u64 {
+//!         let concurrent = &concurrent;
+//!         scope.spawn(move || {
+//!             for value in thread * 250..(thread + 1) * 250 {
+//!                 concurrent.fetch_insert_with_siphashes13_relaxed(value);
+//!             }
+//!         });
+//!     }
+//! });
+//!
+//! let minhash: MinHash<u64, 128> = concurrent.into();
+//!
+//! assert_eq!(minhash, (0..1000_u64).collect());
+//! ```
+
+use core::hash::Hash;
+use core::ops::Index;
+use core::sync::atomic::Ordering;
+
+use crate::prelude::*;
+
+/// MinHash whose words are atomics, so that values can be inserted through a shared reference.
+///
+/// The insertions are provided by the [`AtomicMinHash`] trait, and the resulting
+/// [`MinHash`] is recovered with [`ConcurrentMinHash::into_minhash`] or [`ConcurrentMinHash::load`].
+#[derive(Debug)]
+pub struct ConcurrentMinHash<AtomicWord, const PERMUTATIONS: usize> {
+    words: [AtomicWord; PERMUTATIONS],
+}
+
+impl<AtomicWord: AtomicFetchMin, const PERMUTATIONS: usize> Default
+    for ConcurrentMinHash<AtomicWord, PERMUTATIONS>
+where
+    AtomicWord::Word: Maximal,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<AtomicWord: AtomicFetchMin, const PERMUTATIONS: usize>
+    ConcurrentMinHash<AtomicWord, PERMUTATIONS>
+where
+    AtomicWord::Word: Maximal,
+{
+    /// Create a new empty ConcurrentMinHash.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::sync::atomic::AtomicU16;
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let concurrent = ConcurrentMinHash::<AtomicU16, 16>::new();
+    ///
+    /// assert_eq!(concurrent.into_minhash(), MinHash::<u16, 16>::new());
+    /// ```
+    pub fn new() -> Self {
+        Self::from(MinHash::new())
+    }
+}
+
+impl<AtomicWord: AtomicFetchMin, const PERMUTATIONS: usize>
+    ConcurrentMinHash<AtomicWord, PERMUTATIONS>
+{
+    /// Returns a MinHash with the words loaded with the provided ordering.
+    ///
+    /// # Arguments
+    /// * `ordering` - The ordering to use.
+    ///
+    /// # Implementative details
+    /// Each word is loaded atomically, but while other threads are still inserting
+    /// the returned MinHash is not a snapshot of a single instant: a concurrent
+    /// insertion may be reflected in some of its words and not in the others.
+    /// Once the inserting threads have been joined, the relaxed ordering suffices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::sync::atomic::{AtomicU32, Ordering};
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let concurrent = ConcurrentMinHash::<AtomicU32, 64>::new();
+    /// concurrent.fetch_insert_with_siphashes13(42, Ordering::Relaxed);
+    ///
+    /// let mut minhash = MinHash::<u32, 64>::new();
+    /// minhash.insert_with_siphashes13(42);
+    ///
+    /// assert_eq!(concurrent.load(Ordering::Relaxed), minhash);
+    /// ```
+    pub fn load(&self, ordering: Ordering) -> MinHash<AtomicWord::Word, PERMUTATIONS> {
+        MinHash::from_words(core::array::from_fn(|permutation| {
+            self.words[permutation].load_word(ordering)
+        }))
+    }
+
+    /// Returns the MinHash, consuming the ConcurrentMinHash.
+    pub fn into_minhash(self) -> MinHash<AtomicWord::Word, PERMUTATIONS> {
+        MinHash::from_words(self.words.map(AtomicFetchMin::into_word))
+    }
+}
+
+impl<AtomicWord: AtomicFetchMin, const PERMUTATIONS: usize>
+    From<MinHash<AtomicWord::Word, PERMUTATIONS>> for ConcurrentMinHash<AtomicWord, PERMUTATIONS>
+{
+    fn from(minhash: MinHash<AtomicWord::Word, PERMUTATIONS>) -> Self {
+        Self {
+            words: minhash.into_words().map(AtomicWord::from_word),
+        }
+    }
+}
+
+impl<AtomicWord: AtomicFetchMin, const PERMUTATIONS: usize>
+    From<ConcurrentMinHash<AtomicWord, PERMUTATIONS>> for MinHash<AtomicWord::Word, PERMUTATIONS>
+{
+    fn from(concurrent: ConcurrentMinHash<AtomicWord, PERMUTATIONS>) -> Self {
+        concurrent.into_minhash()
+    }
+}
+
+impl<AtomicWord: AtomicFetchMin, const PERMUTATIONS: usize>
+    IterHashes<AtomicWord::Word, PERMUTATIONS> for ConcurrentMinHash<AtomicWord, PERMUTATIONS>
+where
+    AtomicWord::Word: Min + XorShift + Copy + Eq,
+    u64: Primitive<AtomicWord::Word>,
+{
+}
+
+impl<AtomicWord: AtomicFetchMin, const PERMUTATIONS: usize> AtomicMinHash<AtomicWord, PERMUTATIONS>
+    for ConcurrentMinHash<AtomicWord, PERMUTATIONS>
+where
+    AtomicWord::Word: Min + XorShift + Copy + Eq,
+    u64: Primitive<AtomicWord::Word>,
+{
+    fn iter_atomic<'a>(&'a self) -> impl Iterator<Item = &'a AtomicWord>
+    where
+        AtomicWord: 'a,
+        Self: 'a,
+    {
+        self.words.iter()
+    }
+}
+
+/// Array of [`ConcurrentMinHash`], to insert values into any of its MinHashes from several threads.
+#[derive(Debug)]
+pub struct ConcurrentMinHashArray<AtomicWord, const PERMUTATIONS: usize, const N: usize> {
+    counters: [ConcurrentMinHash<AtomicWord, PERMUTATIONS>; N],
+}
+
+impl<AtomicWord: AtomicFetchMin, const PERMUTATIONS: usize, const N: usize> Default
+    for ConcurrentMinHashArray<AtomicWord, PERMUTATIONS, N>
+where
+    AtomicWord::Word: Maximal,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<AtomicWord: AtomicFetchMin, const PERMUTATIONS: usize, const N: usize>
+    ConcurrentMinHashArray<AtomicWord, PERMUTATIONS, N>
+where
+    AtomicWord::Word: Maximal,
+{
+    /// Create a new ConcurrentMinHashArray of empty MinHashes.
+    pub fn new() -> Self {
+        Self {
+            counters: core::array::from_fn(|_| ConcurrentMinHash::new()),
+        }
+    }
+}
+
+impl<AtomicWord: AtomicFetchMin, const PERMUTATIONS: usize, const N: usize>
+    ConcurrentMinHashArray<AtomicWord, PERMUTATIONS, N>
+{
+    /// Returns a MinHashArray with the words loaded with the provided ordering.
+    ///
+    /// # Arguments
+    /// * `ordering` - The ordering to use.
+    ///
+    /// # Implementative details
+    /// As for [`ConcurrentMinHash::load`], the returned array is not a snapshot
+    /// of a single instant while other threads are still inserting.
+    pub fn load(&self, ordering: Ordering) -> MinHashArray<AtomicWord::Word, PERMUTATIONS, N> {
+        MinHashArray::from_minhashes(core::array::from_fn(|index| {
+            self.counters[index].load(ordering)
+        }))
+    }
+
+    /// Returns the MinHashArray, consuming the ConcurrentMinHashArray.
+    pub fn into_minhash_array(self) -> MinHashArray<AtomicWord::Word, PERMUTATIONS, N> {
+        MinHashArray::from_minhashes(self.counters.map(ConcurrentMinHash::into_minhash))
+    }
+}
+
+impl<AtomicWord: AtomicFetchMin, const PERMUTATIONS: usize, const N: usize>
+    ConcurrentMinHashArray<AtomicWord, PERMUTATIONS, N>
+where
+    AtomicWord::Word: Min + XorShift + Copy + Eq,
+    u64: Primitive<AtomicWord::Word>,
+{
+    /// Insert a value atomically into the MinHash at the provided index, using the SipHasher13.
+    ///
+    /// # Arguments
+    /// * `index` - The index of the MinHash to insert the value into.
+    /// * `value` - The value to insert.
+    /// * `ordering` - The ordering to use.
+    ///
+    /// # Implementative details
+    /// Several threads can insert values into the same or different MinHashes of
+    /// a shared array. As for the single MinHash, the relaxed ordering is sufficient
+    /// when the array is read after joining the inserting threads.
+    ///
+    /// # Panics
+    /// If the index is not smaller than the number of MinHashes in the array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::sync::atomic::{AtomicU64, Ordering};
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let array = ConcurrentMinHashArray::<AtomicU64, 128, 4>::new();
+    ///
+    /// std::thread::scope(|scope| {
+    ///     for thread in 0..4_u64 {
+    ///         let array = &array;
+    ///         scope.spawn(move || {
+    ///             for value in thread * 1000..(thread + 1) * 1000 {
+    ///                 array.fetch_insert_with_siphashes13(value as usize % 4, value, Ordering::Relaxed);
+    ///             }
+    ///         });
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(
+    ///     array.into_minhash_array()[1],
+    ///     (0..4000_u64).filter(|value| value % 4 == 1).collect()
+    /// );
+    /// ```
+    pub fn fetch_insert_with_siphashes13<H: Hash>(
+        &self,
+        index: usize,
+        value: H,
+        ordering: Ordering,
+    ) {
+        assert!(
+            index < N,
+            "The index ({}) must be smaller than the number of MinHashes ({}).",
+            index,
+            N
+        );
+        self.counters[index].fetch_insert_with_siphashes13(value, ordering);
+    }
+}
+
+impl<AtomicWord: AtomicFetchMin, const PERMUTATIONS: usize, const N: usize>
+    From<MinHashArray<AtomicWord::Word, PERMUTATIONS, N>>
+    for ConcurrentMinHashArray<AtomicWord, PERMUTATIONS, N>
+{
+    fn from(array: MinHashArray<AtomicWord::Word, PERMUTATIONS, N>) -> Self {
+        Self {
+            counters: array.into_minhashes().map(ConcurrentMinHash::from),
+        }
+    }
+}
+
+impl<AtomicWord: AtomicFetchMin, const PERMUTATIONS: usize, const N: usize>
+    From<ConcurrentMinHashArray<AtomicWord, PERMUTATIONS, N>>
+    for MinHashArray<AtomicWord::Word, PERMUTATIONS, N>
+{
+    fn from(concurrent: ConcurrentMinHashArray<AtomicWord, PERMUTATIONS, N>) -> Self {
+        concurrent.into_minhash_array()
+    }
+}
+
+impl<AtomicWord, const PERMUTATIONS: usize, const N: usize> Index<usize>
+    for ConcurrentMinHashArray<AtomicWord, PERMUTATIONS, N>
+{
+    type Output = ConcurrentMinHash<AtomicWord, PERMUTATIONS>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.counters[index]
+    }
+}
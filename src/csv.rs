@@ -0,0 +1,100 @@
+//! Module providing the export and import of MinHashArrays as CSV.
+//!
+//! # Format
+//! Each MinHash of the array is written as a row, in the order of the array,
+//! with its `PERMUTATIONS` words written in decimal and separated by commas.
+//! There is no header, and each row is terminated by a newline. Reading checks
+//! that there are exactly `N` rows of exactly `PERMUTATIONS` words each.
+//!
+//! # Examples
+//!
+//! ```
+//! use minhash_rs::prelude::*;
+//!
+//! let mut array = MinHashArray::<u8, 4, 2>::new();
+//! array[0] = MinHash::from_words([1, 2, 3, 4]);
+//!
+//! let mut buffer = Vec::new();
+//! array.write_csv(&mut buffer).unwrap();
+//!
+//! assert_eq!(buffer, b"1,2,3,4\n255,255,255,255\n");
+//! assert_eq!(MinHashArray::read_csv(&mut buffer.as_slice()).unwrap(), array);
+//! ```
+
+use core::fmt::Display;
+use core::str::FromStr;
+use std::format;
+use std::io::{BufRead, BufReader, Error, ErrorKind, Read, Result, Write};
+use std::string::String;
+
+use crate::prelude::{Maximal, MinHashArray};
+
+impl<Word: Maximal + Display + FromStr, const PERMUTATIONS: usize, const N: usize>
+    MinHashArray<Word, PERMUTATIONS, N>
+{
+    /// Write the MinHashArray as CSV, one row per MinHash.
+    ///
+    /// # Arguments
+    /// * `writer` - The writer to write the CSV into.
+    pub fn write_csv<W: Write>(&self, writer: &mut W) -> Result<()> {
+        for minhash in self.iter() {
+            for (i, word) in minhash.iter().enumerate() {
+                if i > 0 {
+                    writer.write_all(b",")?;
+                }
+                write!(writer, "{}", word)?;
+            }
+            writer.write_all(b"\n")?;
+        }
+        Ok(())
+    }
+
+    /// Read a MinHashArray from CSV, as written by [`MinHashArray::write_csv`].
+    ///
+    /// # Arguments
+    /// * `reader` - The reader to read the CSV from.
+    ///
+    /// # Returns
+    /// An error of kind `InvalidData` when the number of rows is not `N`, when a row
+    /// does not have `PERMUTATIONS` words, or when a word cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// assert!(MinHashArray::<u8, 2, 1>::read_csv(&mut "1,2\n".as_bytes()).is_ok());
+    /// assert!(MinHashArray::<u8, 2, 1>::read_csv(&mut "1,2,3\n".as_bytes()).is_err());
+    /// assert!(MinHashArray::<u8, 2, 1>::read_csv(&mut "1,256\n".as_bytes()).is_err());
+    /// assert!(MinHashArray::<u8, 2, 2>::read_csv(&mut "1,2\n".as_bytes()).is_err());
+    /// ```
+    pub fn read_csv<R: Read>(reader: &mut R) -> Result<Self> {
+        let invalid = |message: String| Error::new(ErrorKind::InvalidData, message);
+        let mut array = Self::new();
+        let mut rows = 0;
+        for line in BufReader::new(reader).lines() {
+            let line = line?;
+            if rows == N {
+                return Err(invalid(format!("Expected {} rows, found more.", N)));
+            }
+            let columns = line.split(',').count();
+            if columns != PERMUTATIONS {
+                return Err(invalid(format!(
+                    "Expected {} words in row {}, found {}.",
+                    PERMUTATIONS, rows, columns
+                )));
+            }
+            for (word, field) in array[rows].iter_mut().zip(line.split(',')) {
+                *word = field
+                    .trim()
+                    .parse()
+                    .map_err(|_| invalid(format!("Invalid word {:?} in row {}.", field, rows)))?;
+            }
+            rows += 1;
+        }
+        if rows != N {
+            return Err(invalid(format!("Expected {} rows, found {}.", N, rows)));
+        }
+        Ok(array)
+    }
+}
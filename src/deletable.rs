@@ -0,0 +1,137 @@
+//! Module providing a MinHash supporting the removal of values.
+//!
+//! # How does it work?
+//! The [`DeletableMinHash`] stores, alongside each word, the 64-bit hash of the value
+//! that contributed it. Removing a value resets to maximal all the words it contributed.
+//!
+//! # Limitations
+//! A MinHash only keeps the minimum of each permutation, and not the next smallest one:
+//! once the minimum of a word is removed, the minimum of the remaining values is lost.
+//! The word is therefore reset to maximal, as if no value had ever been inserted in that
+//! permutation, and estimates are biased until the remaining values are inserted again.
+//! Similarly, when two values yield the same word, only the first one is recorded as
+//! its source, and removing the other one leaves the word unchanged.
+
+use core::hash::Hash;
+
+use siphasher::sip128::SipHasher13;
+
+use crate::hash_iter::{hash_value, narrow, permutation_hash};
+use crate::prelude::{Maximal, MinHash, Primitive};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DeletableMinHash<Word, const PERMUTATIONS: usize> {
+    minhash: MinHash<Word, PERMUTATIONS>,
+    sources: [u64; PERMUTATIONS],
+}
+
+impl<Word: Maximal, const PERMUTATIONS: usize> Default for DeletableMinHash<Word, PERMUTATIONS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Word: Maximal, const PERMUTATIONS: usize> DeletableMinHash<Word, PERMUTATIONS> {
+    /// Create a new DeletableMinHash.
+    pub fn new() -> Self {
+        Self {
+            minhash: MinHash::new(),
+            sources: [0; PERMUTATIONS],
+        }
+    }
+}
+
+impl<Word, const PERMUTATIONS: usize> DeletableMinHash<Word, PERMUTATIONS> {
+    /// Returns the underlying MinHash.
+    pub fn minhash(&self) -> &MinHash<Word, PERMUTATIONS> {
+        &self.minhash
+    }
+}
+
+impl<Word: Maximal + Ord, const PERMUTATIONS: usize> DeletableMinHash<Word, PERMUTATIONS>
+where
+    u64: Primitive<Word>,
+{
+    /// Insert a value into the DeletableMinHash using the SipHasher13.
+    ///
+    /// # Arguments
+    /// * `value` - The value to insert.
+    ///
+    /// # Implementative details
+    /// The words are the same as the ones of [`MinHash::insert_with_siphashes13`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut deletable = DeletableMinHash::<u64, 128>::new();
+    ///
+    /// for value in 0..1000_u64 {
+    ///     deletable.insert(value);
+    /// }
+    ///
+    /// assert_eq!(deletable.minhash(), &(0..1000_u64).collect());
+    /// ```
+    pub fn insert<H: Hash>(&mut self, value: H) {
+        let hash = hash_value(value, SipHasher13::new());
+        for (permutation, (word, source)) in self
+            .minhash
+            .iter_mut()
+            .zip(self.sources.iter_mut())
+            .enumerate()
+        {
+            let candidate = narrow(permutation_hash(hash, permutation));
+            if candidate < *word {
+                *word = candidate;
+                *source = hash;
+            }
+        }
+    }
+
+    /// Remove a value from the DeletableMinHash, resetting the words it contributed.
+    ///
+    /// # Arguments
+    /// * `value` - The value to remove.
+    ///
+    /// # Returns
+    /// The number of words that were reset to maximal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut deletable = DeletableMinHash::<u64, 128>::new();
+    ///
+    /// deletable.insert(42);
+    /// assert!(deletable.may_contain(42));
+    ///
+    /// assert_eq!(deletable.remove(42), 128);
+    /// assert!(!deletable.may_contain(42));
+    /// assert!(deletable.minhash().is_empty());
+    /// ```
+    pub fn remove<H: Hash>(&mut self, value: H) -> usize {
+        let hash = hash_value(value, SipHasher13::new());
+        let mut removed = 0;
+        for (word, source) in self.minhash.iter_mut().zip(self.sources.iter()) {
+            if *source == hash && *word != Word::maximal() {
+                *word = Word::maximal();
+                removed += 1;
+            }
+        }
+        removed
+    }
+
+    /// Returns whether the DeletableMinHash may contain the provided value, using the SipHasher13.
+    ///
+    /// # Arguments
+    /// * `value` - The value to check.
+    pub fn may_contain<H: Hash>(&self, value: H) -> bool {
+        let hash = hash_value(value, SipHasher13::new());
+        self.minhash
+            .iter()
+            .enumerate()
+            .all(|(permutation, word)| *word <= narrow(permutation_hash(hash, permutation)))
+    }
+}
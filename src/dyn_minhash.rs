@@ -0,0 +1,183 @@
+//! Module providing a MinHash whose number of permutations is known only at runtime.
+//!
+//! # When should I use this?
+//! The [`MinHash`] type requires the number of permutations to be known at compile
+//! time, which allows for better performance. When the number of permutations
+//! is instead read at runtime, for instance from a configuration file, the
+//! [`DynMinHash`] can be used instead, at the cost of an heap allocation.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::hash::Hash;
+
+use siphasher::sip128::SipHasher13;
+
+use crate::prelude::{HashIter, Maximal, Min, MinHash, Primitive, XorShift};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DynMinHash<Word> {
+    words: Vec<Word>,
+    permutations: usize,
+}
+
+impl<Word: Maximal> DynMinHash<Word> {
+    /// Create a new DynMinHash.
+    ///
+    /// # Arguments
+    /// * `permutations` - The number of permutations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash = DynMinHash::<u64>::new(128);
+    ///
+    /// assert_eq!(minhash.number_of_permutations(), 128);
+    /// ```
+    pub fn new(permutations: usize) -> Self {
+        Self {
+            words: vec![Word::maximal(); permutations],
+            permutations,
+        }
+    }
+}
+
+impl<Word: Min + XorShift + Copy> DynMinHash<Word>
+where
+    u64: Primitive<Word>,
+{
+    /// Insert a value into the DynMinHash using the SipHasher13.
+    ///
+    /// # Arguments
+    /// * `value` - The value to insert.
+    ///
+    /// # Examples
+    /// The DynMinHash produces the same words of a MinHash with the same number of permutations.
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut dynamic = DynMinHash::<u64>::new(128);
+    /// let mut minhash = MinHash::<u64, 128>::new();
+    ///
+    /// dynamic.insert_with_siphashes13(42);
+    /// minhash.insert_with_siphashes13(42);
+    ///
+    /// assert_eq!(dynamic, DynMinHash::from(minhash));
+    /// ```
+    pub fn insert_with_siphashes13<H: Hash>(&mut self, value: H) {
+        for (word, hash) in
+            self.words
+                .iter_mut()
+                .zip(HashIter::new(value, SipHasher13::new(), self.permutations))
+        {
+            word.set_min(hash);
+        }
+    }
+}
+
+impl<Word> DynMinHash<Word> {
+    /// Iterate over the words.
+    pub fn iter(&self) -> impl Iterator<Item = &Word> {
+        self.words.iter()
+    }
+
+    /// Returns the number of permutations.
+    pub fn number_of_permutations(&self) -> usize {
+        self.permutations
+    }
+
+    /// Returns memory required to store the words of the DynMinHash in bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash = DynMinHash::<u32>::new(128);
+    ///
+    /// assert_eq!(minhash.memory(), MinHash::<u32, 128>::new().memory());
+    /// ```
+    pub fn memory(&self) -> usize {
+        self.permutations * core::mem::size_of::<Word>() * 8
+    }
+}
+
+impl<Word: Eq> DynMinHash<Word> {
+    /// Calculate the similarity between two DynMinHashes.
+    ///
+    /// # Arguments
+    /// * `other` - The other DynMinHash to compare to.
+    ///
+    /// # Panics
+    /// If the two DynMinHashes have a different number of permutations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut first = DynMinHash::<u64>::new(128);
+    /// let mut second = DynMinHash::<u64>::new(128);
+    /// let mut first_static = MinHash::<u64, 128>::new();
+    /// let mut second_static = MinHash::<u64, 128>::new();
+    ///
+    /// for i in 0..100_u64 {
+    ///     first.insert_with_siphashes13(i);
+    ///     second.insert_with_siphashes13(i + 50);
+    ///     first_static.insert_with_siphashes13(i);
+    ///     second_static.insert_with_siphashes13(i + 50);
+    /// }
+    ///
+    /// assert_eq!(
+    ///     first.estimate_jaccard_index(&second),
+    ///     first_static.estimate_jaccard_index(&second_static)
+    /// );
+    /// ```
+    ///
+    /// Comparing DynMinHashes with a different number of permutations panics:
+    ///
+    /// ```should_panic
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let first = DynMinHash::<u64>::new(128);
+    /// let second = DynMinHash::<u64>::new(64);
+    ///
+    /// first.estimate_jaccard_index(&second);
+    /// ```
+    pub fn estimate_jaccard_index(&self, other: &Self) -> f64 {
+        assert_eq!(
+            self.permutations, other.permutations,
+            "The two DynMinHashes must have the same number of permutations."
+        );
+        self.words
+            .iter()
+            .zip(other.words.iter())
+            .map(|(l, r)| (l == r) as usize)
+            .sum::<usize>() as f64
+            / self.permutations as f64
+    }
+}
+
+impl<Word, const PERMUTATIONS: usize> From<MinHash<Word, PERMUTATIONS>> for DynMinHash<Word> {
+    /// Create a new DynMinHash from a MinHash.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash: MinHash<u64, 128> = (0..1000_u64).collect();
+    /// let dynamic = DynMinHash::from(minhash);
+    ///
+    /// assert_eq!(dynamic.number_of_permutations(), 128);
+    /// assert!(dynamic.iter().eq(minhash.iter()));
+    /// ```
+    fn from(minhash: MinHash<Word, PERMUTATIONS>) -> Self {
+        Self {
+            words: Vec::from(minhash.into_words()),
+            permutations: PERMUTATIONS,
+        }
+    }
+}
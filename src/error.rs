@@ -0,0 +1,24 @@
+//! Module providing the errors returned by the fallible conversions into MinHashes.
+
+use core::fmt;
+
+/// The error returned when the number of provided words differs from the number of permutations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LengthMismatch {
+    /// The number of permutations of the MinHash.
+    pub expected: usize,
+    /// The number of provided words.
+    pub found: usize,
+}
+
+impl fmt::Display for LengthMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Expected {} words, one per permutation, but {} were provided.",
+            self.expected, self.found
+        )
+    }
+}
+
+impl core::error::Error for LengthMismatch {}
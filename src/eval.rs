@@ -0,0 +1,79 @@
+//! Module providing helpers to evaluate the estimates of the MinHash against exact sets.
+//! This module requires the `std` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::collections::HashSet;
+//! use minhash_rs::eval::{exact_jaccard, jaccard_error};
+//! use minhash_rs::prelude::*;
+//!
+//! let first: HashSet<u64> = (0..1000).collect();
+//! let second: HashSet<u64> = (500..1500).collect();
+//!
+//! let first_minhash: MinHash<u64, 256> = first.iter().collect();
+//! let second_minhash: MinHash<u64, 256> = second.iter().collect();
+//!
+//! let truth = exact_jaccard(&first, &second);
+//! let error = jaccard_error(first_minhash.estimate_jaccard_index(&second_minhash), truth);
+//!
+//! assert_eq!(truth, 500.0 / 1500.0);
+//! assert!(error < 0.1, "Error: {}", error);
+//! ```
+
+use core::hash::Hash;
+use std::collections::HashSet;
+
+/// Returns the exact Jaccard index of the two provided sets.
+///
+/// # Arguments
+/// * `a` - The first set.
+/// * `b` - The second set.
+///
+/// # Implementative details
+/// As for [`MinHash::estimate_jaccard_index`](crate::minhash::MinHash::estimate_jaccard_index),
+/// the Jaccard index of two empty sets is undefined, and `f64::NAN` is returned.
+/// The intersection is counted by iterating over the smaller set.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashSet;
+/// use minhash_rs::eval::exact_jaccard;
+///
+/// let first: HashSet<u8> = [1, 2, 3].into_iter().collect();
+/// let second: HashSet<u8> = [2, 3, 4].into_iter().collect();
+///
+/// assert_eq!(exact_jaccard(&first, &second), 0.5);
+/// assert!(exact_jaccard::<u8>(&HashSet::new(), &HashSet::new()).is_nan());
+/// ```
+pub fn exact_jaccard<T: Eq + Hash>(a: &HashSet<T>, b: &HashSet<T>) -> f64 {
+    let (smaller, larger) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+    let intersection = smaller
+        .iter()
+        .filter(|value| larger.contains(value))
+        .count();
+    let union = a.len() + b.len() - intersection;
+    if union == 0 {
+        return f64::NAN;
+    }
+    intersection as f64 / union as f64
+}
+
+/// Returns the absolute error of the provided Jaccard index estimate.
+///
+/// # Arguments
+/// * `estimate` - The estimated Jaccard index.
+/// * `truth` - The exact Jaccard index.
+///
+/// # Examples
+///
+/// ```
+/// use minhash_rs::eval::jaccard_error;
+///
+/// assert_eq!(jaccard_error(0.25, 0.5), 0.25);
+/// assert_eq!(jaccard_error(0.75, 0.5), 0.25);
+/// ```
+pub fn jaccard_error(estimate: f64, truth: f64) -> f64 {
+    (estimate - truth).abs()
+}
@@ -1,4 +1,4 @@
-use std::hash::Hash;
+use core::hash::Hash;
 
 use crate::prelude::{Maximal, Min, MinHash, Primitive, XorShift};
 
@@ -31,3 +31,80 @@ where
         hll
     }
 }
+
+impl<Word: Min + Clone + Eq + Maximal + XorShift, A: Hash, const PERMUTATATIONS: usize>
+    core::iter::Extend<A> for MinHash<Word, PERMUTATATIONS>
+where
+    u64: Primitive<Word>,
+{
+    #[inline(always)]
+    /// Adds all elements from an iterator to the MinHash.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let data = (0..1000_u64).collect::<Vec<_>>();
+    /// let full = MinHash::<u64, 128>::from_iter(data.iter());
+    ///
+    /// let mut partial = MinHash::<u64, 128>::from_iter(data[..500].iter());
+    /// partial.extend(data[500..].iter());
+    ///
+    /// assert_eq!(full, partial);
+    ///
+    /// // Extending with elements that are already present leaves the MinHash unchanged.
+    /// partial.extend(data.iter());
+    ///
+    /// assert_eq!(full, partial);
+    /// ```
+    fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
+        for item in iter {
+            self.insert_with_siphashes13(item);
+        }
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<
+        Word: Min + Clone + Eq + Maximal + XorShift + Send,
+        A: Hash + Send,
+        const PERMUTATATIONS: usize,
+    > rayon::iter::FromParallelIterator<A> for MinHash<Word, PERMUTATATIONS>
+where
+    u64: Primitive<Word>,
+{
+    /// Creates a new MinHash and adds all elements from a parallel iterator to it.
+    ///
+    /// # Implementative details
+    /// Each thread builds a partial MinHash from its share of the elements,
+    /// and the partial MinHashes are then merged with the element-wise minimum.
+    /// Since the merge is associative and commutative, the result is identical
+    /// to the one of the sequential `from_iter`, regardless of the number of threads.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    /// use rayon::prelude::*;
+    ///
+    /// let parallel: MinHash<u64, 128> = (0..100_000_u64).into_par_iter().collect();
+    /// let sequential: MinHash<u64, 128> = (0..100_000_u64).collect();
+    ///
+    /// assert_eq!(parallel, sequential);
+    /// ```
+    fn from_par_iter<I: rayon::iter::IntoParallelIterator<Item = A>>(par_iter: I) -> Self {
+        use rayon::iter::ParallelIterator;
+
+        par_iter
+            .into_par_iter()
+            .fold(Self::new, |mut minhash, item| {
+                minhash.insert_with_siphashes13(item);
+                minhash
+            })
+            .reduce(Self::new, |mut left, right| {
+                left &= right;
+                left
+            })
+    }
+}
@@ -0,0 +1,36 @@
+//! Module providing the generation of arbitrary MinHashes with the arbitrary crate, for fuzzing.
+//!
+//! # Implementative details
+//! The words of the MinHash are filled, in order, from the unstructured bytes,
+//! so that any sequence of `PERMUTATIONS` words can be generated, including the
+//! ones that are not the MinHash of any set, such as partially empty MinHashes.
+//!
+//! # Examples
+//!
+//! ```
+//! use arbitrary::{Arbitrary, Unstructured};
+//! use minhash_rs::prelude::*;
+//!
+//! let bytes = [1, 0, 2, 0, 3, 0, 4, 0];
+//! let mut unstructured = Unstructured::new(&bytes);
+//!
+//! let minhash = MinHash::<u16, 4>::arbitrary(&mut unstructured).unwrap();
+//!
+//! assert_eq!(minhash.into_words(), [1, 2, 3, 4]);
+//! ```
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::prelude::MinHash;
+
+impl<'a, Word: Arbitrary<'a>, const PERMUTATIONS: usize> Arbitrary<'a>
+    for MinHash<Word, PERMUTATIONS>
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.arbitrary().map(Self::from_words)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[Word; PERMUTATIONS]>::size_hint(depth)
+    }
+}
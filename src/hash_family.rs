@@ -0,0 +1,78 @@
+//! Module providing the selection at runtime of the hasher used by a MinHash.
+//!
+//! # Why should I use this?
+//! MinHashes built with different hashers are not comparable, and a value
+//! inserted with one hasher is not found when queried with another one.
+//! When the hasher is read from a configuration, the [`HashFamily`] allows to
+//! match on it in a single place, rather than at every insertion and query.
+
+use core::hash::Hash;
+
+use crate::atomic::IterHashes;
+use crate::prelude::{Min, MinHash, Primitive, XorShift};
+
+/// The hashers that can be selected at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum HashFamily {
+    /// The SipHasher13, as used by [`MinHash::insert_with_siphashes13`].
+    #[default]
+    Sip13,
+    /// The FVN, as used by [`MinHash::insert_with_fvn`].
+    Fnv,
+}
+
+impl<Word: Min + XorShift + Copy + Eq, const PERMUTATIONS: usize> MinHash<Word, PERMUTATIONS>
+where
+    Self: IterHashes<Word, PERMUTATIONS>,
+    u64: Primitive<Word>,
+{
+    /// Insert a value into the MinHash using the provided hash family.
+    ///
+    /// # Arguments
+    /// * `value` - The value to insert.
+    /// * `family` - The hash family to use.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut minhash = MinHash::<u64, 128>::new();
+    ///
+    /// minhash.insert_with(42, HashFamily::Fnv);
+    ///
+    /// assert!(minhash.may_contain_value_with(42, HashFamily::Fnv));
+    /// assert!(minhash.may_contain_value_with_fvn(42));
+    /// ```
+    pub fn insert_with<H: Hash>(&mut self, value: H, family: HashFamily) {
+        match family {
+            HashFamily::Sip13 => self.insert_with_siphashes13(value),
+            HashFamily::Fnv => self.insert_with_fvn(value),
+        }
+    }
+
+    /// Returns whether the MinHash may contain the provided value, using the provided hash family.
+    ///
+    /// # Arguments
+    /// * `value` - The value to check.
+    /// * `family` - The hash family to use.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut minhash = MinHash::<u64, 128>::new();
+    ///
+    /// minhash.insert_with(42, HashFamily::Sip13);
+    ///
+    /// assert!(minhash.may_contain_value_with(42, HashFamily::Sip13));
+    /// assert!(!minhash.may_contain_value_with(42, HashFamily::Fnv));
+    /// ```
+    pub fn may_contain_value_with<H: Hash>(&self, value: H, family: HashFamily) -> bool {
+        match family {
+            HashFamily::Sip13 => self.may_contain_value_with_siphashes13(value),
+            HashFamily::Fnv => self.may_contain_value_with_fvn(value),
+        }
+    }
+}
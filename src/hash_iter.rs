@@ -0,0 +1,180 @@
+//! Module providing the iterator over the hashes of a value.
+//!
+//! # Are there permutation coefficients to precompute?
+//! No. The permutations are not affine maps `a_i * x + b_i` with coefficients
+//! to be generated and cached, but the elements of a SplitMix64 sequence seeded
+//! by the hash of the value, each of which is computed from the seed and the
+//! index of the permutation alone. There is therefore no per-type state, and
+//! the hashes of a value are the same across calls, threads and processes.
+//!
+//! ```
+//! use minhash_rs::prelude::*;
+//!
+//! let first: Vec<u64> = MinHash::<u64, 128>::iter_siphashes13_from_value(42).collect();
+//! let second: Vec<u64> = MinHash::<u64, 128>::iter_siphashes13_from_value(42).collect();
+//!
+//! assert_eq!(first, second);
+//! assert_eq!(
+//!     first[..64],
+//!     MinHash::<u64, 64>::iter_siphashes13_from_value(42).collect::<Vec<u64>>()[..]
+//! );
+//! ```
+
+use core::hash::{Hash, Hasher};
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+use crate::prelude::{Primitive, SplitMix, SplitMix64};
+
+/// The increment of the SplitMix64 sequence, i.e. the odd integer closest to 2^64 divided by the golden ratio.
+pub(crate) const GOLDEN_GAMMA: u64 = 0x9e3779b97f4a7c15;
+
+/// Iterator over the hashes of a value, one for each permutation.
+///
+/// # Implementative details
+/// The hash of the value is used as the seed of a SplitMix64 sequence, whose
+/// `i`-th element is the SplitMix64 finalizer applied to `seed + (i + 1) * GOLDEN_GAMMA`.
+/// Differently from iterating a single XorShift chain, each permutation is therefore
+/// an independent mix of the seed and of its own index, and the resulting
+/// hashes are not correlated, even when they are truncated to narrow words.
+/// Words narrower than 64 bits keep the highest bits of each hash.
+/// Since the state is incremented before being mixed, a value hashing to zero
+/// yields distinct permutations as any other, and needs no special handling.
+///
+/// # Examples
+///
+/// ```
+/// use minhash_rs::prelude::*;
+///
+/// let hashes: HashIter<u64> = MinHash::<u64, 128>::iter_siphashes13_from_value(42);
+///
+/// assert_eq!(hashes.len(), 128);
+///
+/// let hashes: Vec<u64> = hashes.collect();
+///
+/// assert_eq!(hashes.len(), 128);
+///
+/// let array: [u32; 16] = core::array::from_fn({
+///     let mut hashes = MinHash::<u32, 16>::iter_siphashes13_from_value(42);
+///     move |_| hashes.next().unwrap()
+/// });
+///
+/// assert!(MinHash::<u32, 16>::iter_siphashes13_from_value(42).eq(array));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HashIter<Word> {
+    generator: SplitMix64,
+    remaining: usize,
+    _word: PhantomData<Word>,
+}
+
+impl<Word> HashIter<Word> {
+    /// Create a new iterator over the hashes of the provided value.
+    ///
+    /// # Arguments
+    /// * `value` - The value to hash.
+    /// * `hasher` - The hasher to use.
+    /// * `permutations` - The number of hashes to iterate on.
+    pub fn new<H: Hash, HS: Hasher>(value: H, hasher: HS, permutations: usize) -> Self {
+        Self::from_hash(hash_value(value, hasher), permutations)
+    }
+
+    /// Create a new iterator over the hashes derived from an already computed hash of a value.
+    ///
+    /// # Arguments
+    /// * `hash` - The 64-bit hash of the value.
+    /// * `permutations` - The number of hashes to iterate on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::hash::{Hash, Hasher};
+    /// use minhash_rs::prelude::*;
+    /// use siphasher::sip128::SipHasher13;
+    ///
+    /// let mut hasher = SipHasher13::new();
+    /// 42.hash(&mut hasher);
+    ///
+    /// assert!(HashIter::<u64>::from_hash(hasher.finish(), 128)
+    ///     .eq(MinHash::<u64, 128>::iter_siphashes13_from_value(42)));
+    /// ```
+    pub fn from_hash(hash: u64, permutations: usize) -> Self {
+        Self {
+            generator: SplitMix64::new(hash),
+            remaining: permutations,
+            _word: PhantomData,
+        }
+    }
+
+    /// Returns the current state of the SplitMix64 sequence, which before any
+    /// iteration is the hash of the value.
+    pub(crate) fn state(&self) -> u64 {
+        self.generator.state()
+    }
+}
+
+/// Returns the hash of the provided value, which is the seed of its permutations.
+///
+/// # Arguments
+/// * `value` - The value to hash.
+/// * `hasher` - The hasher to use.
+pub(crate) fn hash_value<H: Hash, HS: Hasher>(value: H, mut hasher: HS) -> u64 {
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Returns the word corresponding to the provided hash.
+///
+/// # Implementative details
+/// Words narrower than 64 bits keep the highest bits of the hash, so that the
+/// conversion is monotone: the narrowed minimum of a set of hashes is the minimum
+/// of the narrowed hashes, and MinHashes with different word widths built from
+/// the same set agree on their common highest bits.
+pub(crate) fn narrow<Word>(hash: u64) -> Word
+where
+    u64: Primitive<Word>,
+{
+    let bits = 8 * core::mem::size_of::<Word>();
+    if bits < 64 {
+        (hash >> (64 - bits)).convert()
+    } else {
+        hash.convert()
+    }
+}
+
+/// Returns the hash of the provided permutation, given the hash of the value.
+///
+/// # Arguments
+/// * `seed` - The hash of the value.
+/// * `permutation` - The index of the permutation.
+///
+/// # Implementative details
+/// This is the `permutation`-th output of a [`SplitMix64`] seeded by `seed`, as returned by a [`HashIter`],
+/// computed without iterating on the previous ones.
+pub(crate) fn permutation_hash(seed: u64, permutation: usize) -> u64 {
+    seed.wrapping_add((permutation as u64 + 1).wrapping_mul(GOLDEN_GAMMA))
+        .splitmix()
+}
+
+impl<Word> Iterator for HashIter<Word>
+where
+    u64: Primitive<Word>,
+{
+    type Item = Word;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        Some(narrow(self.generator.next_u64()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<Word> ExactSizeIterator for HashIter<Word> where u64: Primitive<Word> {}
+
+impl<Word> FusedIterator for HashIter<Word> where u64: Primitive<Word> {}
@@ -0,0 +1,157 @@
+//! Module providing a MinHash paired with a HyperLogLog counter.
+//!
+//! # Why pairing them?
+//! The MinHash estimates the Jaccard index well, but its cardinality estimates
+//! have a relative standard error of about `1 / sqrt(PERMUTATIONS)`, while a
+//! [HyperLogLog](https://github.com/LucaCappelletti94/hyperloglog-rs) of comparable
+//! memory is much more accurate. The [`MinHashWithCardinality`] inserts each value
+//! in both data structures during a single pass, and answers each query with the
+//! data structure best suited for it.
+//! This module requires the `hll` feature.
+
+use core::hash::Hash;
+
+use hyperloglog_rs::prelude::{HyperLogLog, HyperLogLogTrait, Precision, WordType};
+
+use crate::prelude::{Maximal, Min, MinHash, Primitive, XorShift};
+
+#[derive(Debug, Clone, Copy)]
+pub struct MinHashWithCardinality<
+    Word,
+    const PERMUTATIONS: usize,
+    PRECISION: Precision + WordType<BITS>,
+    const BITS: usize,
+> {
+    minhash: MinHash<Word, PERMUTATIONS>,
+    hll: HyperLogLog<PRECISION, BITS>,
+}
+
+impl<
+        Word: Maximal,
+        const PERMUTATIONS: usize,
+        PRECISION: Precision + WordType<BITS>,
+        const BITS: usize,
+    > Default for MinHashWithCardinality<Word, PERMUTATIONS, PRECISION, BITS>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<
+        Word: Maximal,
+        const PERMUTATIONS: usize,
+        PRECISION: Precision + WordType<BITS>,
+        const BITS: usize,
+    > MinHashWithCardinality<Word, PERMUTATIONS, PRECISION, BITS>
+{
+    /// Create a new empty MinHash paired with an empty HyperLogLog.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hyperloglog_rs::prelude::Precision12;
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let paired = MinHashWithCardinality::<u64, 128, Precision12, 6>::new();
+    ///
+    /// assert_eq!(paired.estimate_cardinality(), 0.0);
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            minhash: MinHash::new(),
+            hll: HyperLogLog::default(),
+        }
+    }
+
+    /// Returns the MinHash.
+    pub fn minhash(&self) -> &MinHash<Word, PERMUTATIONS> {
+        &self.minhash
+    }
+
+    /// Returns the HyperLogLog counter.
+    pub fn hll(&self) -> &HyperLogLog<PRECISION, BITS> {
+        &self.hll
+    }
+
+    /// Returns the estimated cardinality of the set, from the HyperLogLog counter.
+    pub fn estimate_cardinality(&self) -> f64 {
+        self.hll.estimate_cardinality() as f64
+    }
+}
+
+impl<
+        Word: Maximal + Eq,
+        const PERMUTATIONS: usize,
+        PRECISION: Precision + WordType<BITS>,
+        const BITS: usize,
+    > MinHashWithCardinality<Word, PERMUTATIONS, PRECISION, BITS>
+{
+    /// Calculate the similarity between the two sets, from the MinHashes.
+    ///
+    /// # Arguments
+    /// * `other` - The other paired MinHash to compare to.
+    pub fn estimate_jaccard_index(&self, other: &Self) -> f64 {
+        self.minhash.estimate_jaccard_index(&other.minhash)
+    }
+}
+
+impl<
+        Word: Min + XorShift + Copy + Eq,
+        const PERMUTATIONS: usize,
+        PRECISION: Precision + WordType<BITS>,
+        const BITS: usize,
+    > MinHashWithCardinality<Word, PERMUTATIONS, PRECISION, BITS>
+where
+    u64: Primitive<Word>,
+{
+    /// Insert a value in both the MinHash, using the SipHasher13, and the HyperLogLog.
+    ///
+    /// # Arguments
+    /// * `value` - The value to insert.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hyperloglog_rs::prelude::Precision12;
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut first = MinHashWithCardinality::<u64, 128, Precision12, 6>::new();
+    /// let mut second = MinHashWithCardinality::<u64, 128, Precision12, 6>::new();
+    ///
+    /// for value in 0..1000_u64 {
+    ///     first.insert(value);
+    ///     second.insert(value + 500);
+    /// }
+    ///
+    /// assert!((first.estimate_cardinality() - 1000.0).abs() < 50.0);
+    /// assert!((first.estimate_jaccard_index(&second) - 1.0 / 3.0).abs() < 0.15);
+    /// assert_eq!(
+    ///     first.estimate_jaccard_index(&second),
+    ///     first.minhash().estimate_jaccard_index(second.minhash())
+    /// );
+    /// ```
+    pub fn insert<H: Hash>(&mut self, value: H) {
+        self.minhash.insert_with_siphashes13(&value);
+        self.hll.insert(&value);
+    }
+}
+
+impl<
+        Word: Min + XorShift + Copy + Eq + Maximal,
+        const PERMUTATIONS: usize,
+        PRECISION: Precision + WordType<BITS>,
+        const BITS: usize,
+        H: Hash,
+    > core::iter::FromIterator<H> for MinHashWithCardinality<Word, PERMUTATIONS, PRECISION, BITS>
+where
+    u64: Primitive<Word>,
+{
+    fn from_iter<I: IntoIterator<Item = H>>(iter: I) -> Self {
+        let mut paired = Self::new();
+        for value in iter {
+            paired.insert(value);
+        }
+        paired
+    }
+}
@@ -1,4 +1,4 @@
-use std::ops::{BitAnd, BitAndAssign};
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign};
 
 use crate::prelude::{Maximal, Min, MinHash};
 
@@ -40,6 +40,72 @@ impl<Word: Min + Clone + Eq, const PERMUTATATIONS: usize> BitAnd<&Self>
     }
 }
 
+/// The union of the sets of two MinHashes, i.e. the element-wise minimum of their words.
+///
+/// # Examples
+///
+/// ```rust
+/// use minhash_rs::prelude::*;
+///
+/// let first: MinHash<u64, 128> = (0..1000_u64).collect();
+/// let second: MinHash<u64, 128> = (500..1500_u64).collect();
+/// let (first_copy, second_copy) = (first, second);
+///
+/// let union = &first | &second;
+///
+/// assert_eq!(first, first_copy);
+/// assert_eq!(second, second_copy);
+/// assert_eq!(union, first | second);
+/// assert_eq!(union, first & second);
+///
+/// let mut merged = first;
+/// merged |= &second;
+/// assert_eq!(merged, union);
+/// ```
+impl<Word: Min + Clone + Eq, const PERMUTATATIONS: usize> BitOrAssign<&Self>
+    for MinHash<Word, PERMUTATATIONS>
+{
+    fn bitor_assign(&mut self, rhs: &Self) {
+        self.bitand_assign(rhs);
+    }
+}
+
+impl<Word: Min + Clone + Eq, const PERMUTATATIONS: usize> BitOrAssign<Self>
+    for MinHash<Word, PERMUTATATIONS>
+{
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.bitor_assign(&rhs);
+    }
+}
+
+impl<Word: Min + Clone + Eq, const PERMUTATATIONS: usize> BitOr for MinHash<Word, PERMUTATATIONS> {
+    type Output = Self;
+
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self.bitor_assign(rhs);
+        self
+    }
+}
+
+impl<Word: Min + Clone + Eq, const PERMUTATATIONS: usize> BitOr<&Self>
+    for MinHash<Word, PERMUTATATIONS>
+{
+    type Output = Self;
+
+    fn bitor(mut self, rhs: &Self) -> Self::Output {
+        self.bitor_assign(rhs);
+        self
+    }
+}
+
+impl<Word: Min + Clone + Eq, const PERMUTATATIONS: usize> BitOr for &MinHash<Word, PERMUTATATIONS> {
+    type Output = MinHash<Word, PERMUTATATIONS>;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.clone() | rhs
+    }
+}
+
 pub trait MinHashIterator<Word: Min + Eq, const PERMUTATIONS: usize> {
     /// Returns a MinHash that is the intersection of all MinHashes in the iterator.
     ///
@@ -49,6 +115,30 @@ pub trait MinHashIterator<Word: Min + Eq, const PERMUTATIONS: usize> {
     /// use minhash_rs::prelude::*;
     /// ```
     fn intersection(self) -> MinHash<Word, PERMUTATIONS>;
+
+    /// Returns the MinHash of the union of the sets of all the MinHashes in the iterator.
+    ///
+    /// # Implementative details
+    /// The result starts from an empty MinHash, whose words are all maximal,
+    /// and is folded with the element-wise minimum of the words. An empty
+    /// iterator therefore returns an empty MinHash.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let first: MinHash<u64, 128> = (0..1000_u64).collect();
+    /// let second: MinHash<u64, 128> = (500..2000_u64).collect();
+    /// let third: MinHash<u64, 128> = (10_000..10_500_u64).collect();
+    ///
+    /// let union = [first, second, third].into_iter().union_all();
+    /// let combined: MinHash<u64, 128> = (0..2000_u64).chain(10_000..10_500).collect();
+    ///
+    /// assert_eq!(union, combined);
+    /// assert!(core::iter::empty::<MinHash<u64, 128>>().union_all().is_empty());
+    /// ```
+    fn union_all(self) -> MinHash<Word, PERMUTATIONS>;
 }
 
 impl<
@@ -64,4 +154,11 @@ impl<
         }
         result
     }
+
+    fn union_all(self) -> MinHash<Word, PERMUTATIONS> {
+        self.fold(MinHash::default(), |mut union, minhash| {
+            union &= minhash;
+            union
+        })
+    }
 }
@@ -1,27 +1,100 @@
 #![feature(return_position_impl_trait_in_trait)]
+#![cfg_attr(feature = "simd", feature(portable_simd))]
+#![no_std]
 #![doc = include_str!("../README.md")]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "rkyv")]
+pub mod archive;
 pub mod atomic;
+#[cfg(feature = "alloc")]
+pub mod bbit_minhash;
+pub mod builder;
+pub mod cardinality;
+pub mod concurrent;
+#[cfg(feature = "csv")]
+pub mod csv;
+pub mod deletable;
+#[cfg(feature = "alloc")]
+pub mod dyn_minhash;
+pub mod error;
+#[cfg(feature = "std")]
+pub mod eval;
 pub mod from_iter;
+#[cfg(feature = "arbitrary")]
+pub mod fuzz;
+pub mod hash_family;
+pub mod hash_iter;
+#[cfg(feature = "hll")]
+pub mod hll;
 pub mod intersection;
+#[cfg(feature = "alloc")]
+pub mod lsh;
 pub mod maximal;
 pub mod min;
 pub mod minhash;
 pub mod minhash_array;
+pub mod minhash_like;
+pub mod one_bit_minhash;
+pub mod one_permutation;
+pub mod online;
+pub mod prepared;
 pub mod primitive;
+#[cfg(feature = "alloc")]
+pub mod quantized;
+#[cfg(feature = "rand")]
+pub mod random;
+#[cfg(feature = "serde")]
+pub mod serialize;
+#[cfg(feature = "simd")]
+pub mod simd;
 pub mod splitmix;
+pub mod to_bytes;
+pub mod weighted_minhash;
 pub mod xorshift;
 pub mod zero;
 
 pub mod prelude {
     pub use crate::atomic::*;
+    #[cfg(feature = "alloc")]
+    pub use crate::bbit_minhash::BBitMinHash;
+    pub use crate::builder::MinHashBuilder;
+    pub use crate::cardinality::*;
+    pub use crate::concurrent::{ConcurrentMinHash, ConcurrentMinHashArray};
+    pub use crate::deletable::DeletableMinHash;
+    #[cfg(feature = "alloc")]
+    pub use crate::dyn_minhash::DynMinHash;
+    pub use crate::error::LengthMismatch;
+    pub use crate::hash_family::HashFamily;
+    pub use crate::hash_iter::HashIter;
+    #[cfg(feature = "hll")]
+    pub use crate::hll::MinHashWithCardinality;
     pub use crate::intersection::*;
+    #[cfg(feature = "alloc")]
+    pub use crate::lsh::LshIndex;
     pub use crate::maximal::Maximal;
     pub use crate::min::Min;
-    pub use crate::minhash::MinHash;
+    #[cfg(feature = "rkyv")]
+    pub use crate::minhash::ArchivedMinHash;
+    pub use crate::minhash::{
+        expected_std_error, jaccard_common_prefix, permutations_for_error, MinHash,
+    };
     pub use crate::minhash_array::*;
+    pub use crate::minhash_like::MinHashLike;
+    pub use crate::one_bit_minhash::OneBitMinHash;
+    pub use crate::one_permutation::OnePermutationMinHash;
+    pub use crate::online::OnlineMinHash;
+    pub use crate::prepared::PreparedMinHash;
     pub use crate::primitive::Primitive;
-    pub use crate::splitmix::SplitMix;
+    #[cfg(feature = "alloc")]
+    pub use crate::quantized::Quantized;
+    pub use crate::splitmix::{SplitMix, SplitMix64};
+    pub use crate::to_bytes::ToBytes;
+    pub use crate::weighted_minhash::WeightedMinHash;
     pub use crate::xorshift::XorShift;
     pub use crate::zero::Zero;
 }
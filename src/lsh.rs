@@ -0,0 +1,170 @@
+//! Module providing locality-sensitive hashing over MinHashes.
+//!
+//! # How does it work?
+//! Comparing a MinHash against all the others is quadratic in the number of
+//! MinHashes. The LSH banding technique splits each MinHash into `BANDS` bands
+//! of `ROWS` consecutive words, and hashes each band into a bucket. Two MinHashes
+//! become candidates as soon as they share a bucket in at least one band, which
+//! happens with probability `1 - (1 - J^ROWS)^BANDS`, where `J` is their Jaccard index.
+//! The number of bands and rows therefore tunes the similarity threshold
+//! above which MinHashes are likely to be retrieved.
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::hash::{Hash, Hasher};
+
+use siphasher::sip128::SipHasher13;
+
+use crate::prelude::MinHash;
+
+#[derive(Debug, Clone)]
+pub struct LshIndex<Id, Word, const PERMUTATIONS: usize, const BANDS: usize, const ROWS: usize> {
+    buckets: [BTreeMap<u64, Vec<Id>>; BANDS],
+    _word: core::marker::PhantomData<Word>,
+}
+
+impl<Id, Word, const PERMUTATIONS: usize, const BANDS: usize, const ROWS: usize>
+    LshIndex<Id, Word, PERMUTATIONS, BANDS, ROWS>
+{
+    /// Compile-time check that the bands and rows cover exactly the permutations.
+    const VALID_CONFIGURATION: () = assert!(
+        BANDS * ROWS == PERMUTATIONS,
+        "The number of bands times the number of rows must be equal to the number of permutations."
+    );
+
+    /// Create a new empty LshIndex.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let index = LshIndex::<usize, u64, 128, 32, 4>::new();
+    ///
+    /// assert_eq!(index.bands(), 32);
+    /// assert_eq!(index.rows(), 4);
+    /// ```
+    ///
+    /// A configuration whose bands and rows do not cover the permutations does not compile:
+    ///
+    /// ```compile_fail
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let index = LshIndex::<usize, u64, 128, 32, 8>::new();
+    /// ```
+    pub fn new() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_CONFIGURATION;
+        Self {
+            buckets: core::array::from_fn(|_| BTreeMap::new()),
+            _word: core::marker::PhantomData,
+        }
+    }
+
+    /// Returns the number of bands.
+    pub fn bands(&self) -> usize {
+        BANDS
+    }
+
+    /// Returns the number of rows in each band.
+    pub fn rows(&self) -> usize {
+        ROWS
+    }
+}
+
+impl<Id, Word, const PERMUTATIONS: usize, const BANDS: usize, const ROWS: usize> Default
+    for LshIndex<Id, Word, PERMUTATIONS, BANDS, ROWS>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<
+        Id: Clone + Ord,
+        Word: Hash,
+        const PERMUTATIONS: usize,
+        const BANDS: usize,
+        const ROWS: usize,
+    > LshIndex<Id, Word, PERMUTATIONS, BANDS, ROWS>
+{
+    /// Iterate on the hashes of the bands of the provided MinHash.
+    ///
+    /// # Arguments
+    /// * `minhash` - The MinHash to split into bands.
+    fn iter_band_hashes(minhash: &MinHash<Word, PERMUTATIONS>) -> impl Iterator<Item = u64> + '_ {
+        minhash.as_ref().chunks_exact(ROWS).map(|band| {
+            let mut hasher = SipHasher13::new();
+            band.hash(&mut hasher);
+            hasher.finish()
+        })
+    }
+
+    /// Insert a MinHash in the index with the provided identifier.
+    ///
+    /// # Arguments
+    /// * `id` - The identifier of the MinHash.
+    /// * `minhash` - The MinHash to insert.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut index = LshIndex::<&str, u64, 128, 32, 4>::new();
+    ///
+    /// let first: MinHash<u64, 128> = (0..1000_u64).collect();
+    /// let second: MinHash<u64, 128> = (50..1050_u64).collect();
+    /// let third: MinHash<u64, 128> = (5000..6000_u64).collect();
+    ///
+    /// index.insert("first", &first);
+    /// index.insert("third", &third);
+    ///
+    /// assert_eq!(index.query(&second), vec!["first"]);
+    /// ```
+    pub fn insert(&mut self, id: Id, minhash: &MinHash<Word, PERMUTATIONS>) {
+        for (buckets, hash) in self.buckets.iter_mut().zip(Self::iter_band_hashes(minhash)) {
+            buckets.entry(hash).or_default().push(id.clone());
+        }
+    }
+
+    /// Returns the sorted identifiers of the MinHashes sharing at least one band bucket with the provided one.
+    ///
+    /// # Arguments
+    /// * `minhash` - The MinHash to query.
+    ///
+    /// # Implementative details
+    /// The returned identifiers are candidates, and their similarity to the
+    /// queried MinHash should be verified with [`MinHash::estimate_jaccard_index`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut index = LshIndex::<usize, u64, 128, 32, 4>::new();
+    ///
+    /// for i in 0..10_u64 {
+    ///     let minhash: MinHash<u64, 128> = (i * 10_000..i * 10_000 + 1000).collect();
+    ///     index.insert(i as usize, &minhash);
+    /// }
+    ///
+    /// let query: MinHash<u64, 128> = (30_050..31_050_u64).collect();
+    ///
+    /// assert_eq!(index.query(&query), vec![3]);
+    /// assert!(index.query(&MinHash::new()).is_empty());
+    /// ```
+    pub fn query(&self, minhash: &MinHash<Word, PERMUTATIONS>) -> Vec<Id> {
+        let mut candidates: Vec<Id> = self
+            .buckets
+            .iter()
+            .zip(Self::iter_band_hashes(minhash))
+            .filter_map(|(buckets, hash)| buckets.get(&hash))
+            .flatten()
+            .cloned()
+            .collect();
+        candidates.sort_unstable();
+        candidates.dedup();
+        candidates
+    }
+}
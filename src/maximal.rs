@@ -1,33 +1,35 @@
 pub trait Maximal: Copy {
-    fn maximal() -> Self;
-}
+    /// The maximal value, usable in constant contexts.
+    ///
+    /// Since version 0.3.0, this constant must be provided by every implementor,
+    /// and [`Maximal::maximal`] returns it by default.
+    const MAXIMAL: Self;
 
-impl Maximal for u8 {
     fn maximal() -> Self {
-        u8::MAX
+        Self::MAXIMAL
     }
 }
 
+impl Maximal for u8 {
+    const MAXIMAL: Self = u8::MAX;
+}
+
 impl Maximal for u16 {
-    fn maximal() -> Self {
-        u16::MAX
-    }
+    const MAXIMAL: Self = u16::MAX;
 }
 
 impl Maximal for u32 {
-    fn maximal() -> Self {
-        u32::MAX
-    }
+    const MAXIMAL: Self = u32::MAX;
 }
 
 impl Maximal for u64 {
-    fn maximal() -> Self {
-        u64::MAX
-    }
+    const MAXIMAL: Self = u64::MAX;
+}
+
+impl Maximal for usize {
+    const MAXIMAL: Self = usize::MAX;
 }
 
 impl Maximal for u128 {
-    fn maximal() -> Self {
-        u128::MAX
-    }
+    const MAXIMAL: Self = u128::MAX;
 }
@@ -1,19 +1,42 @@
 //! Module providing the MinHash data structure.
+//!
+//! # Resizing MinHashes
+//! Methods that change the number of permutations of a MinHash, such as
+//! [`MinHash::fold_halve`], [`MinHash::subsample`] and [`MinHash::truncate`],
+//! take the number of permutations of the result as an additional const generic
+//! parameter, as stable Rust cannot express arithmetic over const generics in types. The
+//! consistency of the requested sizes is checked at compile time, when the
+//! methods are instantiated, so that a mismatch is a compilation error.
 
 use crate::{
     atomic::IterHashes,
-    prelude::{Min, Primitive},
+    cardinality::{CardinalityEstimator, FiniteSpaceEstimator, KmvEstimator, MleEstimator},
+    error::LengthMismatch,
+    hash_iter::{hash_value, narrow, permutation_hash, GOLDEN_GAMMA},
+    prelude::{HashIter, Min, Primitive, SplitMix},
     xorshift::XorShift,
     zero::Zero,
 };
+#[cfg(feature = "alloc")]
+use alloc::{collections::BinaryHeap, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::cmp::Reverse;
 use core::hash::Hash;
+use core::ops::BitXor;
 use core::ops::Index;
 use core::ops::IndexMut;
+use fnv::FnvHasher;
+use siphasher::sip128::SipHasher13;
 
 use crate::prelude::Maximal;
 
 #[repr(transparent)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(repr(transparent))
+)]
 pub struct MinHash<Word, const PERMUTATIONS: usize> {
     words: [Word; PERMUTATIONS],
 }
@@ -38,6 +61,10 @@ impl<Word: Maximal, const PERMUTATIONS: usize> Default for MinHash<Word, PERMUTA
 impl<Word: Maximal, const PERMUTATIONS: usize> MinHash<Word, PERMUTATIONS> {
     /// Create a new MinHash.
     ///
+    /// # Implementative details
+    /// The words are filled with the [`Maximal::MAXIMAL`] constant, so that
+    /// empty MinHashes can also be created in constant contexts.
+    ///
     /// # Examples
     ///
     /// ```
@@ -45,410 +72,2859 @@ impl<Word: Maximal, const PERMUTATIONS: usize> MinHash<Word, PERMUTATIONS> {
     ///
     /// let mut minhash = MinHash::<u64, 128>::new();
     /// ```
-    pub fn new() -> Self {
-        Self {
-            words: [Word::maximal(); PERMUTATIONS],
-        }
-    }
-}
-
-impl<Word: Min + XorShift + Copy + Eq + Maximal + Zero, const PERMUTATIONS: usize>
-    MinHash<Word, PERMUTATIONS>
-where
-    u64: Primitive<Word>,
-{
-    /// Returns whether the MinHash is empty.
     ///
-    /// # Examples
+    /// An empty MinHash can be declared as a static:
     ///
     /// ```
     /// use minhash_rs::prelude::*;
     ///
-    /// let mut minhash = MinHash::<u8, 16>::new();
+    /// static EMPTY: MinHash<u64, 128> = MinHash::new();
     ///
-    /// assert!(minhash.is_empty());
-    /// minhash.insert_with_siphashes13(42);
-    /// assert!(!minhash.is_empty());
+    /// assert!(EMPTY.is_empty());
     /// ```
-    ///
-    pub fn is_empty(&self) -> bool {
-        self.iter().all(|word| *word == Word::maximal())
+    pub const fn new() -> Self {
+        Self {
+            words: [Word::MAXIMAL; PERMUTATIONS],
+        }
     }
 
-    /// Returns whether the MinHash is fully saturated.
+    /// Reset the MinHash to the empty state, in place.
     ///
     /// # Examples
     ///
     /// ```
     /// use minhash_rs::prelude::*;
     ///
-    /// let mut minhash = MinHash::<u8, 16>::new();
-    ///
-    /// assert!(!minhash.is_full());
+    /// let mut minhash = MinHash::<u64, 128>::new();
     ///
-    /// for i in 0..1024 {
-    ///    minhash.insert_with_siphashes13(i);
+    /// for set in [0..100_u64, 100..200_u64] {
+    ///     for value in set {
+    ///         minhash.insert_with_siphashes13(value);
+    ///     }
+    ///     assert!(!minhash.is_empty());
+    ///     minhash.clear();
+    ///     assert!(minhash.is_empty());
+    ///     assert_eq!(minhash, MinHash::new());
     /// }
-    ///
-    /// assert!(minhash.is_full());
     /// ```
-    ///
-    pub fn is_full(&self) -> bool {
-        self.iter().all(|word| *word == Word::zero())
+    pub fn clear(&mut self) {
+        self.words.fill(Word::maximal());
     }
 }
 
-impl<Word: Min + XorShift + Copy + Eq, const PERMUTATIONS: usize> MinHash<Word, PERMUTATIONS>
-where
-    Self: IterHashes<Word, PERMUTATIONS>,
-    u64: Primitive<Word>,
-{
-    /// Returns whether the MinHash may contain the provided value, using the SipHasher13.
+impl<Word: Maximal + Min, const PERMUTATIONS: usize> MinHash<Word, PERMUTATIONS> {
+    /// Create a new MinHash from the provided per-permutation minima, without hashing.
     ///
     /// # Arguments
-    /// * `value` - The value to check.
+    /// * `minima` - The minimum of each permutation, in the order of the permutations.
     ///
     /// # Implementative details
-    /// The procedure estimates whether the provided value is contained
-    /// in the current MinHash data structure by checking whether all of
-    /// the words are smaller or equal to all of the hash values that
-    /// are calculated using the provided value as seed.
+    /// Each value is applied with `set_min` to the word of its permutation, starting
+    /// from the empty MinHash, so that the result goes through the same update of the
+    /// insertions. Differently from [`MinHash::from_words`], the minima can be provided
+    /// by any iterator, and their number is checked at runtime.
+    ///
+    /// # Panics
+    /// If the number of provided minima is not equal to the number of permutations.
     ///
     /// # Examples
     ///
     /// ```
     /// use minhash_rs::prelude::*;
     ///
-    /// let mut minhash = MinHash::<u64, 128>::new();
+    /// let minhash = MinHash::<u16, 4>::from_minima(vec![3, 1, 4, 1]);
     ///
-    /// assert!(!minhash.may_contain_value_with_siphashes13(42));
-    /// minhash.insert_with_siphashes13(42);
-    /// assert!(minhash.may_contain_value_with_siphashes13(42));
-    /// minhash.insert_with_siphashes13(47);
-    /// assert!(minhash.may_contain_value_with_siphashes13(47));
+    /// assert_eq!(minhash.into_words(), [3, 1, 4, 1]);
     /// ```
     ///
-    pub fn may_contain_value_with_siphashes13<H: Hash>(&self, value: H) -> bool {
-        self.iter()
-            .zip(Self::iter_siphashes13_from_value(value))
-            .all(|(word, hash)| word.is_min(hash))
-    }
-
-    /// Insert a value into the MinHash using the SipHasher13.
-    ///
-    /// # Arguments
-    /// * `value` - The value to insert.
-    ///
-    /// # Examples
-    /// In the following example we show how we can
-    /// create a MinHash and insert a value in it.
-    ///
-    /// ```
+    /// ```should_panic
     /// use minhash_rs::prelude::*;
     ///
-    /// let mut minhash = MinHash::<u64, 128>::new();
-    ///
-    /// assert!(!minhash.may_contain_value_with_siphashes13(42));
-    /// minhash.insert_with_siphashes13(42);
-    /// assert!(minhash.may_contain_value_with_siphashes13(42));
-    /// minhash.insert_with_siphashes13(47);
-    /// assert!(minhash.may_contain_value_with_siphashes13(47));
+    /// let minhash = MinHash::<u16, 4>::from_minima([3, 1, 4]);
     /// ```
-    pub fn insert_with_siphashes13<H: Hash>(&mut self, value: H) {
-        for (word, hash) in self
-            .iter_mut()
-            .zip(Self::iter_siphashes13_from_value(value))
-        {
-            word.set_min(hash);
+    pub fn from_minima<I: IntoIterator<Item = Word>>(minima: I) -> Self {
+        let mut minhash = Self::new();
+        let mut count = 0;
+        for value in minima {
+            assert!(
+                count < PERMUTATIONS,
+                "More minima than the number of permutations ({}) were provided.",
+                PERMUTATIONS
+            );
+            minhash.words[count].set_min(value);
+            count += 1;
         }
+        assert!(
+            count == PERMUTATIONS,
+            "The number of minima ({}) must be equal to the number of permutations ({}).",
+            count,
+            PERMUTATIONS
+        );
+        minhash
     }
+}
 
-    /// Returns whether the MinHash may contain the provided value, using the keyed SipHasher13.
+impl<Word, const PERMUTATIONS: usize> MinHash<Word, PERMUTATIONS> {
+    /// Create a new MinHash from the provided words.
     ///
     /// # Arguments
-    /// * `value` - The value to check.
-    /// * `key0` - The first key.
-    /// * `key1` - The second key.
-    ///
-    /// # Implementative details
-    /// The procedure estimates whether the provided value is contained
-    /// in the current MinHash data structure by checking whether all of
-    /// the words are smaller or equal to all of the hash values that
-    /// are calculated using the provided value as seed.
+    /// * `words` - The words of the MinHash.
     ///
     /// # Examples
     ///
     /// ```
     /// use minhash_rs::prelude::*;
     ///
-    /// let mut minhash = MinHash::<u64, 128>::new();
-    /// let key0 = 0x0123456789ABCDEF;
-    /// let key1 = 0xFEDCBA9876543210;
+    /// let first: MinHash<u64, 128> = (0..1000_u64).collect();
+    /// let second: MinHash<u64, 128> = (500..1500_u64).collect();
     ///
-    /// assert!(!minhash.may_contain_value_with_keyed_siphashes13(42, key0, key1));
-    /// minhash.insert_with_keyed_siphashes13(42, key0, key1);
-    /// assert!(minhash.may_contain_value_with_keyed_siphashes13(42, key0, key1));
-    /// minhash.insert_with_keyed_siphashes13(47, key0, key1);
-    /// assert!(minhash.may_contain_value_with_keyed_siphashes13(47, key0, key1));
-    /// ```
+    /// let first_copy = MinHash::from_words(first.into_words());
+    /// let second_copy = MinHash::from_words(second.into_words());
     ///
-    pub fn may_contain_value_with_keyed_siphashes13<H: Hash>(
-        &self,
-        value: H,
-        key0: u64,
-        key1: u64,
-    ) -> bool {
-        self.iter()
-            .zip(Self::iter_keyed_siphashes13_from_value(value, key0, key1))
-            .all(|(word, hash)| word.is_min(hash))
+    /// assert_eq!(first, first_copy);
+    /// assert_eq!(
+    ///     first.estimate_jaccard_index(&second),
+    ///     first_copy.estimate_jaccard_index(&second_copy)
+    /// );
+    /// ```
+    pub fn from_words(words: [Word; PERMUTATIONS]) -> Self {
+        Self { words }
     }
 
-    /// Insert a value into the MinHash using the keyed SipHasher13.
-    ///
-    /// # Arguments
-    /// * `value` - The value to insert.
-    /// * `key0` - The first key.
-    /// * `key1` - The second key.
+    /// Returns the words of the MinHash.
     ///
     /// # Examples
-    /// In the following example we show how we can
-    /// create a MinHash and insert a value in it.
     ///
     /// ```
     /// use minhash_rs::prelude::*;
     ///
-    /// let mut minhash = MinHash::<u64, 128>::new();
-    /// let key0 = 0x0123456789ABCDEF;
-    /// let key1 = 0xFEDCBA9876543210;
+    /// let minhash = MinHash::<u8, 4>::new();
     ///
-    /// assert!(!minhash.may_contain_value_with_keyed_siphashes13(42, key0, key1));
-    /// minhash.insert_with_keyed_siphashes13(42, key0, key1);
-    /// assert!(minhash.may_contain_value_with_keyed_siphashes13(42, key0, key1));
-    /// minhash.insert_with_keyed_siphashes13(47, key0, key1);
-    /// assert!(minhash.may_contain_value_with_keyed_siphashes13(47, key0, key1));
+    /// assert_eq!(minhash.into_words(), [u8::MAX; 4]);
     /// ```
-    pub fn insert_with_keyed_siphashes13<H: Hash>(&mut self, value: H, key0: u64, key1: u64) {
-        for (word, hash) in self
-            .iter_mut()
-            .zip(Self::iter_keyed_siphashes13_from_value(value, key0, key1))
-        {
-            word.set_min(hash);
-        }
+    pub fn into_words(self) -> [Word; PERMUTATIONS] {
+        self.words
     }
+}
 
-    /// Returns whether the MinHash may contain the provided value, using the FVN.
+impl<Word: Copy, const PERMUTATIONS: usize> MinHash<Word, PERMUTATIONS> {
+    /// Create a new MinHash from the provided slice of words.
     ///
     /// # Arguments
-    /// * `value` - The value to check.
+    /// * `words` - The words of the MinHash.
     ///
-    /// # Implementative details
-    /// The procedure estimates whether the provided value is contained
-    /// in the current MinHash data structure by checking whether all of
-    /// the words are smaller or equal to all of the hash values that
-    /// are calculated using the provided value as seed.
+    /// # Returns
+    /// `None` when the length of the slice is not equal to `PERMUTATIONS`.
     ///
     /// # Examples
     ///
     /// ```
     /// use minhash_rs::prelude::*;
     ///
-    /// let mut minhash = MinHash::<u64, 128>::new();
+    /// let minhash: MinHash<u64, 128> = (0..1000_u64).collect();
     ///
-    /// assert!(!minhash.may_contain_value_with_fvn(42));
-    /// minhash.insert_with_fvn(42);
-    /// assert!(minhash.may_contain_value_with_fvn(42));
-    /// minhash.insert_with_fvn(47);
-    /// assert!(minhash.may_contain_value_with_fvn(47));
+    /// assert_eq!(MinHash::try_from_slice(minhash.as_ref()), Some(minhash));
+    /// assert_eq!(MinHash::<u64, 64>::try_from_slice(minhash.as_ref()), None);
     /// ```
-    ///
-    pub fn may_contain_value_with_fvn<H: Hash>(&self, value: H) -> bool {
-        self.iter()
-            .zip(Self::iter_fvn_from_value(value))
-            .all(|(word, hash)| word.is_min(hash))
+    pub fn try_from_slice(words: &[Word]) -> Option<Self> {
+        words.try_into().ok().map(Self::from_words)
     }
+}
 
-    /// Insert a value into the MinHash using the FVN.
-    ///
-    /// # Arguments
-    /// * `value` - The value to insert.
+impl<Word: Eq + Maximal, const PERMUTATIONS: usize> MinHash<Word, PERMUTATIONS> {
+    /// Returns whether the MinHash is empty.
     ///
     /// # Examples
-    /// In the following example we show how we can
-    /// create a MinHash and insert a value in it.
     ///
     /// ```
     /// use minhash_rs::prelude::*;
     ///
-    /// let mut minhash = MinHash::<u64, 128>::new();
+    /// let mut minhash = MinHash::<u8, 16>::new();
     ///
-    /// assert!(!minhash.may_contain_value_with_fvn(42));
-    /// minhash.insert_with_fvn(42);
-    /// assert!(minhash.may_contain_value_with_fvn(42));
-    /// minhash.insert_with_fvn(47);
-    /// assert!(minhash.may_contain_value_with_fvn(47));
+    /// assert!(minhash.is_empty());
+    /// minhash.insert_with_siphashes13(42);
+    /// assert!(!minhash.is_empty());
     /// ```
-    pub fn insert_with_fvn<H: Hash>(&mut self, value: H) {
-        for (word, hash) in self.iter_mut().zip(Self::iter_fvn_from_value(value)) {
-            word.set_min(hash);
-        }
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.iter().all(|word| *word == Word::maximal())
     }
 
-    /// Returns whether the MinHash may contain the provided value, using the keyed FVN.
-    ///
-    /// # Arguments
-    /// * `value` - The value to check.
-    /// * `key` - The first key.
+    /// Returns the number of words that are still maximal, as in an empty MinHash.
     ///
     /// # Implementative details
-    /// The procedure estimates whether the provided value is contained
-    /// in the current MinHash data structure by checking whether all of
-    /// the words are smaller or equal to all of the hash values that
-    /// are calculated using the provided value as seed.
+    /// Together with [`MinHash::saturated_word_count`], it characterizes the
+    /// words that are neither unset nor saturated, which are the ones carrying
+    /// information on the set.
     ///
     /// # Examples
     ///
     /// ```
     /// use minhash_rs::prelude::*;
     ///
-    /// let mut minhash = MinHash::<u64, 128>::new();
-    /// let key = 0x0123456789ABCDEF;
+    /// let mut minhash = MinHash::<u8, 16>::new();
     ///
-    /// assert!(!minhash.may_contain_value_with_keyed_fvn(42, key));
-    /// minhash.insert_with_keyed_fvn(42, key);
-    /// assert!(minhash.may_contain_value_with_keyed_fvn(42, key));
-    /// minhash.insert_with_keyed_fvn(47, key);
-    /// assert!(minhash.may_contain_value_with_keyed_fvn(47, key));
-    /// ```
+    /// assert_eq!(minhash.unset_word_count(), 16);
     ///
-    pub fn may_contain_value_with_keyed_fvn<H: Hash>(&self, value: H, key: u64) -> bool {
-        self.iter()
-            .zip(Self::iter_keyed_fvn_from_value(value, key))
-            .all(|(word, hash)| word.is_min(hash))
+    /// for i in 0..100 {
+    ///     minhash.insert_with_siphashes13(i);
+    /// }
+    ///
+    /// assert_eq!(minhash.unset_word_count(), 0);
+    /// ```
+    pub fn unset_word_count(&self) -> usize {
+        self.iter().filter(|word| **word == Word::maximal()).count()
     }
 
-    /// Insert a value into the MinHash using the keyed FVN.
+    /// Returns the fraction of words that are no longer maximal.
     ///
-    /// # Arguments
-    /// * `value` - The value to insert.
-    /// * `key` - The first key.
+    /// # Implementative details
+    /// A word stops being maximal as soon as any value is inserted, so this
+    /// fraction is zero for an empty MinHash and reaches one quickly, well
+    /// before the MinHash is full. It is a quick gauge of how many permutations
+    /// carry information, which is mostly relevant for small words.
     ///
     /// # Examples
-    /// In the following example we show how we can
-    /// create a MinHash and insert a value in it.
     ///
     /// ```
     /// use minhash_rs::prelude::*;
     ///
-    /// let mut minhash = MinHash::<u64, 128>::new();
-    /// let key = 0x0123456789ABCDEF;
+    /// let mut minhash = MinHash::<u8, 16>::new();
     ///
-    /// assert!(!minhash.may_contain_value_with_keyed_fvn(42, key));
-    /// minhash.insert_with_keyed_fvn(42, key);
-    /// assert!(minhash.may_contain_value_with_keyed_fvn(42, key));
-    /// minhash.insert_with_keyed_fvn(47, key);
-    /// assert!(minhash.may_contain_value_with_keyed_fvn(47, key));
+    /// assert_eq!(minhash.fill_fraction(), 0.0);
+    ///
+    /// for i in 0..3 {
+    ///     minhash.insert_with_siphashes13(i);
+    /// }
+    ///
+    /// let fill_fraction = minhash.fill_fraction();
+    /// assert!(fill_fraction > 0.0 && fill_fraction <= 1.0);
     /// ```
-    pub fn insert_with_keyed_fvn<H: Hash>(&mut self, value: H, key: u64) {
-        for (word, hash) in self
-            .iter_mut()
-            .zip(Self::iter_keyed_fvn_from_value(value, key))
-        {
-            word.set_min(hash);
-        }
+    pub fn fill_fraction(&self) -> f64 {
+        self.iter().filter(|word| **word != Word::maximal()).count() as f64 / PERMUTATIONS as f64
     }
 }
 
-impl<Word, const PERMUTATIONS: usize> MinHash<Word, PERMUTATIONS> {
-    /// Iterate over the words.
-    pub fn iter(&self) -> impl Iterator<Item = &Word> {
-        self.words.iter()
-    }
-
-    /// Iterate over the words mutably.
-    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Word> {
-        self.words.iter_mut()
-    }
-
-    /// Returns the number of permutations.
+impl<Word: Ord + Copy + Maximal, const PERMUTATIONS: usize> MinHash<Word, PERMUTATIONS> {
+    /// Returns the smallest word of the MinHash.
     ///
     /// # Examples
     ///
     /// ```
     /// use minhash_rs::prelude::*;
     ///
-    /// let minhash = MinHash::<u64, 128>::new();
+    /// let mut minhash = MinHash::<u8, 16>::new();
     ///
-    /// assert_eq!(minhash.number_of_permutations(), 128);
+    /// assert_eq!(minhash.min_word(), u8::MAX);
+    ///
+    /// minhash.insert_with_siphashes13(42);
+    ///
+    /// assert_eq!(minhash.min_word(), *minhash.iter().min().unwrap());
     /// ```
-    pub fn number_of_permutations(&self) -> usize {
-        PERMUTATIONS
+    pub fn min_word(&self) -> Word {
+        self.iter().copied().min().unwrap_or(Word::maximal())
     }
 
-    /// Returns memory required to store the MinHash in bits.
+    /// Returns the largest word of the MinHash.
     ///
     /// # Examples
-    /// For a MinHash with 128 permutations and 64 bit words, the memory required is 128 * 64 * 8.
     ///
     /// ```
     /// use minhash_rs::prelude::*;
     ///
-    /// let minhash = MinHash::<u64, 128>::new();
+    /// let mut minhash = MinHash::<u8, 16>::new();
     ///
-    /// assert_eq!(minhash.memory(), 128 * 64);
-    /// ```
+    /// assert_eq!(minhash.max_word(), u8::MAX);
     ///
-    /// For a MinHash with 128 permutations and 32 bit words, the memory required is 128 * 32 * 8.
+    /// for i in 0..4096 {
+    ///     minhash.insert_with_siphashes13(i);
+    /// }
+    ///
+    /// assert_eq!(minhash.max_word(), 0);
+    /// ```
+    pub fn max_word(&self) -> Word {
+        self.iter().copied().max().unwrap_or(Word::maximal())
+    }
+
+    /// Reset to maximal every word larger than the provided threshold.
+    ///
+    /// # Arguments
+    /// * `threshold` - The largest word to keep.
+    ///
+    /// # Implementative details
+    /// Each word is the minimum of the permuted hashes of the elements of the set,
+    /// so a word is kept if and only if at least one element has a permuted hash not
+    /// larger than the threshold. The rarefied word is therefore exactly the one that
+    /// would have been obtained inserting only such elements, that is a subsample of
+    /// the set including each element with probability `threshold / Word::maximal()`,
+    /// drawn independently for each permutation. This is the same cut used by the
+    /// k-minimum-values sketches, which keep only the smallest hashes.
+    ///
+    /// Two rarefied MinHashes will share many words that are maximal in both, which
+    /// do not carry any information on their similarity: compare them with
+    /// [`MinHash::estimate_jaccard_index_dense`], which remains an unbiased estimate
+    /// of the Jaccard index, with a variance growing as fewer words are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut minhash: MinHash<u8, 64> = (0..100_u64).collect();
+    ///
+    /// minhash.rarefy(4);
+    ///
+    /// assert!(minhash.iter().all(|word| *word <= 4 || *word == u8::MAX));
+    ///
+    /// minhash.rarefy(0);
+    ///
+    /// assert!(minhash.iter().all(|word| *word == 0 || *word == u8::MAX));
+    /// ```
+    pub fn rarefy(&mut self, threshold: Word) {
+        for word in self.iter_mut() {
+            if *word > threshold {
+                *word = Word::maximal();
+            }
+        }
+    }
+
+    /// Returns the lengths of the runs of equal words, once sorted.
+    fn iter_word_multiplicities(&self) -> impl Iterator<Item = usize> {
+        let mut words = self.words;
+        words.sort_unstable();
+        let mut start = 0;
+        (0..PERMUTATIONS).filter_map(move |i| {
+            if i + 1 == PERMUTATIONS || words[i] != words[i + 1] {
+                let multiplicity = i + 1 - start;
+                start = i + 1;
+                Some(multiplicity)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns the number of distinct words in the MinHash.
+    ///
+    /// # Implementative details
+    /// The words of independent permutations are independent, so for large
+    /// words almost all of them are distinct. Many repeated words, outside of
+    /// the saturation of small words, signal correlated permutations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash: MinHash<u64, 128> = (0..1000_u64).collect();
+    ///
+    /// assert_eq!(minhash.distinct_word_count(), 128);
+    /// assert_eq!(MinHash::<u64, 128>::new().distinct_word_count(), 1);
+    /// ```
+    pub fn distinct_word_count(&self) -> usize {
+        self.iter_word_multiplicities().count()
+    }
+
+    /// Returns the Shannon entropy, in bits, of the histogram of the words of the MinHash.
+    ///
+    /// # Implementative details
+    /// The entropy is zero when all the words are equal, as in an empty MinHash, and
+    /// it is at most `log2(PERMUTATIONS)`, which is reached when all the words are distinct.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash: MinHash<u64, 128> = (0..1000_u64).collect();
+    ///
+    /// assert!((minhash.word_entropy() - 7.0).abs() < 1e-9);
+    /// assert_eq!(MinHash::<u64, 128>::new().word_entropy(), 0.0);
+    /// ```
+    pub fn word_entropy(&self) -> f64 {
+        self.iter_word_multiplicities()
+            .map(|multiplicity| {
+                let probability = multiplicity as f64 / PERMUTATIONS as f64;
+                -probability * libm::log2(probability)
+            })
+            .sum::<f64>()
+            .max(0.0)
+    }
+}
+
+impl<Word: Eq + Zero, const PERMUTATIONS: usize> MinHash<Word, PERMUTATIONS> {
+    /// Returns whether the MinHash is fully saturated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut minhash = MinHash::<u8, 16>::new();
+    ///
+    /// assert!(!minhash.is_full());
+    ///
+    /// for i in 0..4096 {
+    ///    minhash.insert_with_siphashes13(i);
+    /// }
+    ///
+    /// assert!(minhash.is_full());
+    /// ```
+    ///
+    pub fn is_full(&self) -> bool {
+        self.iter().all(|word| *word == Word::zero())
+    }
+
+    /// Returns the number of words saturated to zero, the smallest value a word can assume.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut minhash = MinHash::<u8, 16>::new();
+    ///
+    /// assert_eq!(minhash.saturated_word_count(), 0);
+    ///
+    /// for i in 0..100 {
+    ///     minhash.insert_with_siphashes13(i);
+    /// }
+    /// let partially_saturated = minhash.saturated_word_count();
+    ///
+    /// for i in 100..4096 {
+    ///     minhash.insert_with_siphashes13(i);
+    /// }
+    ///
+    /// assert!(minhash.saturated_word_count() > partially_saturated);
+    /// assert_eq!(minhash.saturated_word_count(), 16);
+    /// ```
+    pub fn saturated_word_count(&self) -> usize {
+        self.iter().filter(|word| **word == Word::zero()).count()
+    }
+}
+
+impl<Word: Maximal, const PERMUTATIONS: usize> MinHash<Word, PERMUTATIONS> {
+    /// Returns the estimated cardinality of the set using the provided estimator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash: MinHash<u64, 1024> = (0..1000_u64).collect();
+    ///
+    /// let mean = minhash.estimate_cardinality_with::<MeanEstimator>();
+    /// let mle = minhash.estimate_cardinality_with::<MleEstimator>();
+    ///
+    /// assert!((mean - 1000.0).abs() < 100.0);
+    /// assert!((mle - 1000.0).abs() < 100.0);
+    /// ```
+    pub fn estimate_cardinality_with<E: CardinalityEstimator<Word>>(&self) -> f64 {
+        E::estimate(&self.words, Word::maximal())
+    }
+}
+
+impl<Word: Maximal + Eq + Primitive<f64>, const PERMUTATIONS: usize> MinHash<Word, PERMUTATIONS> {
+    /// Returns the estimated cardinality of the set.
+    ///
+    /// # Implementative details
+    /// The words are normalized into the unit interval by dividing them by
+    /// `Word::maximal()`, and the cardinality is estimated using the k-minimum
+    /// values style estimator `(PERMUTATIONS - 1) / sum(word / MAX)`, as
+    /// implemented in the [`KmvEstimator`]. An empty MinHash has cardinality
+    /// `0.0`, while a fully saturated MinHash, whose words are all zero, cannot
+    /// bound the cardinality and returns [`f64::INFINITY`]. Use
+    /// [`MinHash::estimate_cardinality_with`] to select a different estimator,
+    /// such as the [`FiniteSpaceEstimator`] which is better suited for narrow words.
+    ///
+    /// [`FiniteSpaceEstimator`]: crate::cardinality::FiniteSpaceEstimator
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut minhash = MinHash::<u64, 1024>::new();
+    ///
+    /// assert_eq!(minhash.estimate_cardinality(), 0.0);
+    ///
+    /// for i in 0..1000_u64 {
+    ///     minhash.insert_with_siphashes13(i);
+    /// }
+    ///
+    /// let estimate = minhash.estimate_cardinality();
+    ///
+    /// assert!((estimate - 1000.0).abs() < 100.0, "Estimate: {}", estimate);
+    ///
+    /// let saturated = MinHash::<u8, 16>::from_words([0; 16]);
+    ///
+    /// assert_eq!(saturated.estimate_cardinality(), f64::INFINITY);
+    /// ```
+    pub fn estimate_cardinality(&self) -> f64 {
+        self.estimate_cardinality_with::<KmvEstimator>()
+    }
+
+    /// Estimate the fraction of the set of the current MinHash contained in the set of the other one.
+    ///
+    /// # Arguments
+    /// * `other` - The other MinHash to compare to.
+    ///
+    /// # Implementative details
+    /// The cardinalities of the two sets are estimated with [`MinHash::estimate_cardinality`],
+    /// and then combined with the Jaccard index as described in [`MinHash::estimate_containment`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let small: MinHash<u64, 1024> = (0..1000_u64).collect();
+    /// let large: MinHash<u64, 1024> = (0..10_000_u64).collect();
+    ///
+    /// let containment = small.estimate_containment_index(&large);
+    ///
+    /// assert!(containment > 0.8, "Containment: {}", containment);
+    /// assert!(large.estimate_containment_index(&small) < 0.2);
+    /// ```
+    pub fn estimate_containment_index(&self, other: &Self) -> f64 {
+        self.estimate_containment(
+            other,
+            self.estimate_cardinality(),
+            other.estimate_cardinality(),
+        )
+    }
+
+    /// Estimate the Tversky index between the sets of two MinHashes.
+    ///
+    /// # Arguments
+    /// * `other` - The other MinHash to compare to.
+    /// * `alpha` - The weight of the elements of the current set missing from the other one.
+    /// * `beta` - The weight of the elements of the other set missing from the current one.
+    ///
+    /// # Implementative details
+    /// The Tversky index is `|A ∩ B| / (|A ∩ B| + alpha * |A - B| + beta * |B - A|)`.
+    /// The cardinalities of the sets are estimated with [`MinHash::estimate_cardinality`],
+    /// and the cardinality of the intersection as `J * (|A| + |B|) / (1 + J)`, where `J`
+    /// is the estimated Jaccard index, from which the two set differences follow.
+    /// With `alpha = beta = 1` the Tversky index is the Jaccard index, while with
+    /// `alpha = beta = 0.5` it is the Dice coefficient `2 * J / (1 + J)`.
+    /// When the denominator is zero, as for two empty sets, the estimated Jaccard index is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let first: MinHash<u64, 1024> = (0..1000_u64).collect();
+    /// let second: MinHash<u64, 1024> = (500..2000_u64).collect();
+    ///
+    /// let jaccard = first.estimate_jaccard_index(&second);
+    /// let dice = 2.0 * jaccard / (1.0 + jaccard);
+    ///
+    /// assert!((first.estimate_tversky_index(&second, 1.0, 1.0) - jaccard).abs() < 1e-9);
+    /// assert!((first.estimate_tversky_index(&second, 0.5, 0.5) - dice).abs() < 1e-9);
+    ///
+    /// // Ignoring the elements of the second set missing from the first one
+    /// // estimates the containment of the first set in the second one.
+    /// let containment = first.estimate_tversky_index(&second, 1.0, 0.0);
+    ///
+    /// assert!((containment - 0.5).abs() < 0.1, "Containment: {}", containment);
+    /// ```
+    pub fn estimate_tversky_index(&self, other: &Self, alpha: f64, beta: f64) -> f64 {
+        let jaccard = self.estimate_jaccard_index(other);
+        let self_cardinality = self.estimate_cardinality();
+        let other_cardinality = other.estimate_cardinality();
+        let intersection = jaccard * (self_cardinality + other_cardinality) / (1.0 + jaccard);
+        let denominator = intersection
+            + alpha * (self_cardinality - intersection).max(0.0)
+            + beta * (other_cardinality - intersection).max(0.0);
+        if denominator == 0.0 {
+            return jaccard;
+        }
+        intersection / denominator
+    }
+
+    /// Returns a rough projection of how many more distinct elements can be inserted before the given saturation level is reached.
+    ///
+    /// # Arguments
+    /// * `saturation_level` - The fraction of words, between zero and one, that are expected to be saturated.
+    ///
+    /// # Implementative details
+    /// A word is saturated when it reaches zero, which after `n` distinct insertions
+    /// happens with probability `1 - (1 - 1 / (MAX + 1))^n`. Inverting this relation
+    /// yields the number of insertions expected to reach the requested saturation level,
+    /// from which we subtract the cardinality currently estimated with the
+    /// [`FiniteSpaceEstimator`]. The result is a projection based on expected values,
+    /// and should only be used for capacity planning. It is zero when the saturation
+    /// level is already expected to be reached, and infinite for a level of one.
+    ///
+    /// [`FiniteSpaceEstimator`]: crate::cardinality::FiniteSpaceEstimator
+    ///
+    /// # Panics
+    /// If the saturation level is not between zero and one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash: MinHash<u8, 128> = (0..100_u64).collect();
+    /// let projection = minhash.elements_until_saturation(0.5);
+    ///
+    /// // Half of the words of a u8 MinHash are expected to be zero after about 177 insertions.
+    /// assert!(projection.is_finite());
+    /// assert!(projection > 0.0 && projection < 200.0, "Projection: {}", projection);
+    ///
+    /// let minhash: MinHash<u64, 128> = (0..100_u64).collect();
+    ///
+    /// assert!(minhash.elements_until_saturation(0.5) > 1e18);
+    /// assert_eq!(minhash.elements_until_saturation(1.0), f64::INFINITY);
+    /// ```
+    pub fn elements_until_saturation(&self, saturation_level: f64) -> f64 {
+        assert!(
+            (0.0..=1.0).contains(&saturation_level),
+            "The saturation level must be between zero and one."
+        );
+        let space = Primitive::<f64>::convert(Word::maximal()) + 1.0;
+        let target = libm::log1p(-saturation_level) / libm::log1p(-1.0 / space);
+        let current = self.estimate_cardinality_with::<FiniteSpaceEstimator>();
+        (target - current).max(0.0)
+    }
+
+    /// Returns an iterator over the words of the MinHash normalized into the interval `[0, 1)`.
+    ///
+    /// # Implementative details
+    /// Each word is divided by `Word::maximal() + 1`. For a well-built MinHash
+    /// of a set of `n` elements, the normalized words behave as minima of `n`
+    /// independent uniform values. For words wider than the 53 bits of mantissa of
+    /// a double, the largest words would round to one, and are therefore clamped to
+    /// the largest double smaller than one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash: MinHash<u16, 16> = (0..10_u64).collect();
+    ///
+    /// let features: Vec<f64> = minhash.normalized_words().collect();
+    ///
+    /// assert_eq!(features.len(), 16);
+    /// assert!(features.iter().all(|feature| (0.0..1.0).contains(feature)));
+    /// assert!(MinHash::<u64, 4>::new()
+    ///     .normalized_words()
+    ///     .all(|feature| feature < 1.0));
+    /// ```
+    pub fn normalized_words(&self) -> impl Iterator<Item = f64> + '_ {
+        let space = Primitive::<f64>::convert(Word::maximal()) + 1.0;
+        self.iter().map(move |word| {
+            (Primitive::<f64>::convert(*word) / space).min(1.0 - f64::EPSILON / 2.0)
+        })
+    }
+
+    /// Returns the words of the MinHash normalized into the interval `[0, 1)`.
+    ///
+    /// # Implementative details
+    /// The words are normalized as in [`MinHash::normalized_words`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash = MinHash::<u8, 4>::from_words([0, 64, 128, 255]);
+    ///
+    /// assert_eq!(minhash.normalized_minima(), vec![0.0, 0.25, 0.5, 255.0 / 256.0]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn normalized_minima(&self) -> Vec<f64> {
+        self.normalized_words().collect()
+    }
+
+    /// Returns the p-value of the Kolmogorov-Smirnov test of the uniformity of the minima.
+    ///
+    /// # Implementative details
+    /// The minimum of `n` independent uniform values has cumulative distribution
+    /// `1 - (1 - u)^n`, which maps the normalized minima of a well-built MinHash
+    /// to uniform values (probability integral transform). The cardinality `n` is
+    /// estimated with the [`MleEstimator`], and the p-value of the largest distance
+    /// between the empirical distribution of the transformed minima and the uniform
+    /// one is computed with the asymptotic Kolmogorov distribution. Since the
+    /// cardinality is estimated from the same minima, the test is conservative.
+    /// A small p-value signals a degenerate permutation scheme, such as correlated
+    /// permutations, while empty and saturated MinHashes have p-values close to zero.
+    /// The test assumes continuous values, and it is therefore only meaningful for
+    /// words wide enough to make ties unlikely.
+    ///
+    /// [`MleEstimator`]: crate::cardinality::MleEstimator
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash: MinHash<u64, 1024> = (0..1000_u64).collect();
+    ///
+    /// assert!(minhash.uniformity_pvalue() > 0.001);
+    ///
+    /// // A MinHash whose permutations all agree is degenerate.
+    /// let clustered = MinHash::<u64, 1024>::from_words([minhash[0]; 1024]);
+    ///
+    /// assert!(clustered.uniformity_pvalue() < 0.001);
+    /// ```
+    pub fn uniformity_pvalue(&self) -> f64 {
+        let space = Primitive::<f64>::convert(Word::maximal()) + 1.0;
+        let cardinality = self.estimate_cardinality_with::<MleEstimator>();
+        let mut transformed: [f64; PERMUTATIONS] = core::array::from_fn(|i| {
+            let normalized = Primitive::<f64>::convert(self.words[i]) / space;
+            -libm::expm1(cardinality * libm::log1p(-normalized))
+        });
+        transformed.sort_unstable_by(f64::total_cmp);
+
+        let number_of_values = PERMUTATIONS as f64;
+        let statistic = transformed
+            .iter()
+            .enumerate()
+            .map(|(i, value)| {
+                (value - i as f64 / number_of_values).max((i + 1) as f64 / number_of_values - value)
+            })
+            .fold(0.0, f64::max);
+
+        // The asymptotic Kolmogorov distribution, with the finite-sample correction.
+        let root = libm::sqrt(number_of_values);
+        let lambda = (root + 0.12 + 0.11 / root) * statistic;
+        let mut pvalue = 0.0;
+        let mut sign = 2.0;
+        for k in 1..=100 {
+            let term = sign * libm::exp(-2.0 * (k * k) as f64 * lambda * lambda);
+            pvalue += term;
+            if term.abs() < 1e-12 {
+                break;
+            }
+            sign = -sign;
+        }
+        pvalue.clamp(0.0, 1.0)
+    }
+}
+
+impl<Word: Maximal + Min + Eq + Primitive<f64>, const PERMUTATIONS: usize>
+    MinHash<Word, PERMUTATIONS>
+{
+    /// Estimate the cardinality of the union of the sets of two MinHashes.
+    ///
+    /// # Arguments
+    /// * `other` - The other MinHash.
+    ///
+    /// # Implementative details
+    /// The union is the element-wise minimum of the two MinHashes,
+    /// whose cardinality is estimated with [`MinHash::estimate_cardinality`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let first: MinHash<u64, 1024> = (0..1000_u64).collect();
+    /// let second: MinHash<u64, 1024> = (500..1500_u64).collect();
+    ///
+    /// let union = first.estimate_union_cardinality(&second);
+    ///
+    /// assert!((union - 1500.0).abs() < 150.0, "Union: {}", union);
+    /// ```
+    pub fn estimate_union_cardinality(&self, other: &Self) -> f64 {
+        (*self & other).estimate_cardinality()
+    }
+
+    /// Estimate the cardinality of the union of the sets of the MinHash and of all the provided ones.
+    ///
+    /// # Arguments
+    /// * `others` - The other MinHashes.
+    ///
+    /// # Implementative details
+    /// The MinHashes are merged element-wise into a temporary MinHash, which is
+    /// the MinHash of the union of all the sets, and whose cardinality is then
+    /// estimated with [`MinHash::estimate_cardinality`]. Elements shared by several
+    /// sets are therefore counted once, without any inclusion-exclusion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let first: MinHash<u64, 1024> = (0..1000_u64).collect();
+    /// let second: MinHash<u64, 1024> = (500..1500_u64).collect();
+    /// let third: MinHash<u64, 1024> = (1000..2000_u64).collect();
+    ///
+    /// let union = first.estimate_union_cardinality_many(&[&second, &third]);
+    ///
+    /// assert!((union - 2000.0).abs() < 200.0, "Union: {}", union);
+    /// assert_eq!(
+    ///     first.estimate_union_cardinality_many(&[&second]),
+    ///     first.estimate_union_cardinality(&second)
+    /// );
+    /// assert_eq!(first.estimate_union_cardinality_many(&[]), first.estimate_cardinality());
+    /// ```
+    pub fn estimate_union_cardinality_many(&self, others: &[&Self]) -> f64 {
+        others
+            .iter()
+            .fold(*self, |mut union, other| {
+                union &= *other;
+                union
+            })
+            .estimate_cardinality()
+    }
+
+    /// Estimate the cardinality of the intersection of the sets of two MinHashes.
+    ///
+    /// # Arguments
+    /// * `other` - The other MinHash.
+    ///
+    /// # Implementative details
+    /// The cardinality of the intersection is the product of the estimated Jaccard
+    /// index and of the estimated cardinality of the union. The relative errors of
+    /// the two estimates compound: the relative standard error of the Jaccard index
+    /// is `sqrt((1 - J) / (J * PERMUTATIONS))`, which grows quickly as the sets become
+    /// disjoint, so that small intersections are estimated with a large relative error.
+    /// The intersection of two empty sets is empty, even though their Jaccard index is undefined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let first: MinHash<u64, 1024> = (0..1000_u64).collect();
+    /// let second: MinHash<u64, 1024> = (500..1500_u64).collect();
+    ///
+    /// let intersection = first.estimate_intersection_cardinality(&second);
+    ///
+    /// assert!((intersection - 500.0).abs() < 100.0, "Intersection: {}", intersection);
+    /// assert_eq!(MinHash::<u64, 1024>::new().estimate_intersection_cardinality(&MinHash::new()), 0.0);
+    /// ```
+    pub fn estimate_intersection_cardinality(&self, other: &Self) -> f64 {
+        let union = *self & other;
+        if union.is_empty() {
+            return 0.0;
+        }
+        self.estimate_jaccard_index(other) * union.estimate_cardinality()
+    }
+
+    /// Estimate the cardinality of the symmetric difference of the sets of two MinHashes.
+    ///
+    /// # Arguments
+    /// * `other` - The other MinHash.
+    ///
+    /// # Implementative details
+    /// The symmetric difference is the union minus the intersection, i.e.
+    /// `|A| + |B| - 2 * |A ∩ B|`, which is estimated as `(1 - J) * |A ∪ B|`.
+    /// As for the intersection, the errors of the Jaccard index and of the union
+    /// compound, but the relative error is now large for nearly identical sets.
+    /// The symmetric difference of two empty sets is empty, even though their Jaccard
+    /// index is undefined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let first: MinHash<u64, 1024> = (0..1000_u64).collect();
+    /// let second: MinHash<u64, 1024> = (500..1500_u64).collect();
+    ///
+    /// let difference = first.estimate_symmetric_difference_cardinality(&second);
+    ///
+    /// assert!((difference - 1000.0).abs() < 150.0, "Difference: {}", difference);
+    /// assert_eq!(first.estimate_symmetric_difference_cardinality(&first), 0.0);
+    /// assert_eq!(
+    ///     MinHash::<u64, 1024>::new().estimate_symmetric_difference_cardinality(&MinHash::new()),
+    ///     0.0
+    /// );
+    /// ```
+    pub fn estimate_symmetric_difference_cardinality(&self, other: &Self) -> f64 {
+        let union = *self & other;
+        if union.is_empty() {
+            return 0.0;
+        }
+        (1.0 - self.estimate_jaccard_index(other)) * union.estimate_cardinality()
+    }
+}
+
+impl<Word: Min + XorShift + Copy + Eq, const PERMUTATIONS: usize> MinHash<Word, PERMUTATIONS>
+where
+    Self: IterHashes<Word, PERMUTATIONS>,
+    u64: Primitive<Word>,
+{
+    /// Returns whether the MinHash may contain the provided value, using the SipHasher13.
+    ///
+    /// # Arguments
+    /// * `value` - The value to check.
+    ///
+    /// # Implementative details
+    /// The procedure estimates whether the provided value is contained
+    /// in the current MinHash data structure by checking whether all of
+    /// the words are smaller or equal to all of the hash values that
+    /// are calculated using the provided value as seed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut minhash = MinHash::<u64, 128>::new();
+    ///
+    /// assert!(!minhash.may_contain_value_with_siphashes13(42));
+    /// minhash.insert_with_siphashes13(42);
+    /// assert!(minhash.may_contain_value_with_siphashes13(42));
+    /// minhash.insert_with_siphashes13(47);
+    /// assert!(minhash.may_contain_value_with_siphashes13(47));
+    /// ```
+    ///
+    pub fn may_contain_value_with_siphashes13<H: Hash>(&self, value: H) -> bool {
+        self.iter()
+            .zip(Self::iter_siphashes13_from_value(value))
+            .all(|(word, hash)| word.is_min(hash))
+    }
+
+    /// Insert a value into the MinHash using the SipHasher13.
+    ///
+    /// # Arguments
+    /// * `value` - The value to insert.
+    ///
+    /// # Implementative details
+    /// The value is hashed once, and the hash is expanded into the permutations
+    /// by [`MinHash::insert_prehashed`]. The hasher lives on the stack and its
+    /// construction is negligible, so that for a large number of permutations the
+    /// cost of an insertion is dominated by the SplitMix64 finalizer applied once
+    /// per permutation.
+    ///
+    /// # Examples
+    /// In the following example we show how we can
+    /// create a MinHash and insert a value in it.
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut minhash = MinHash::<u64, 128>::new();
+    ///
+    /// assert!(!minhash.may_contain_value_with_siphashes13(42));
+    /// minhash.insert_with_siphashes13(42);
+    /// assert!(minhash.may_contain_value_with_siphashes13(42));
+    /// minhash.insert_with_siphashes13(47);
+    /// assert!(minhash.may_contain_value_with_siphashes13(47));
+    /// ```
+    pub fn insert_with_siphashes13<H: Hash>(&mut self, value: H) {
+        self.insert_prehashed(hash_value(value, SipHasher13::new()));
+    }
+
+    /// Returns whether the MinHash may contain the value with the provided hash.
+    ///
+    /// # Arguments
+    /// * `hash` - The 64-bit hash of the value.
+    ///
+    /// # Implementative details
+    /// The hash is used directly as the seed of the permutations, as described
+    /// in [`MinHash::insert_prehashed`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut minhash = MinHash::<u64, 128>::new();
+    ///
+    /// assert!(!minhash.may_contain_prehashed(0x0123456789ABCDEF));
+    /// minhash.insert_prehashed(0x0123456789ABCDEF);
+    /// assert!(minhash.may_contain_prehashed(0x0123456789ABCDEF));
+    /// ```
+    pub fn may_contain_prehashed(&self, hash: u64) -> bool {
+        self.iter()
+            .zip(HashIter::<Word>::from_hash(hash, PERMUTATIONS))
+            .all(|(word, hash)| word.is_min(hash))
+    }
+
+    /// Insert a value into the MinHash using its already computed 64-bit hash.
+    ///
+    /// # Arguments
+    /// * `hash` - The 64-bit hash of the value.
+    ///
+    /// # Implementative details
+    /// The hash is used directly as the seed of the permutations, skipping the
+    /// hashing of the value. Inserting the hash computed by the SipHasher13 is
+    /// therefore equivalent to inserting the value with [`MinHash::insert_with_siphashes13`].
+    /// The provided hashes should be of good quality, as the permutations of
+    /// values with colliding hashes are identical.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::hash::{Hash, Hasher};
+    /// use minhash_rs::prelude::*;
+    /// use siphasher::sip128::SipHasher13;
+    ///
+    /// let mut prehashed = MinHash::<u64, 128>::new();
+    /// let mut minhash = MinHash::<u64, 128>::new();
+    ///
+    /// for value in 0..1000_u64 {
+    ///     let mut hasher = SipHasher13::new();
+    ///     value.hash(&mut hasher);
+    ///     prehashed.insert_prehashed(hasher.finish());
+    ///     minhash.insert_with_siphashes13(value);
+    /// }
+    ///
+    /// assert_eq!(prehashed, minhash);
+    /// ```
+    pub fn insert_prehashed(&mut self, hash: u64) {
+        for (word, hash) in self
+            .iter_mut()
+            .zip(HashIter::<Word>::from_hash(hash, PERMUTATIONS))
+        {
+            word.set_min(hash);
+        }
+    }
+
+    /// Insert all the values of the provided slice into the MinHash using the SipHasher13.
+    ///
+    /// # Arguments
+    /// * `values` - The values to insert.
+    ///
+    /// # Implementative details
+    /// Inserting the values one by one updates all of the words of the MinHash
+    /// for each value. Here, the values are instead processed in blocks of 64:
+    /// the hashes of the values of a block are computed first, and then each word
+    /// is updated with the minimum of its permutation over the whole block, so that
+    /// the word is loaded and stored once per block, and the inner loop over the
+    /// block only keeps the running minimum in a register. Since the cost of the
+    /// insertion is dominated by the computation of the `PERMUTATIONS` hashes of each
+    /// value, which cannot be avoided, the expected improvement is limited to the
+    /// memory traffic of the words, and the throughput is comparable to the one of
+    /// inserting the values one by one, as shown by the `bench_minhash_insert` bench.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let values: Vec<u64> = (0..1000).collect();
+    ///
+    /// let mut batch = MinHash::<u64, 128>::new();
+    /// batch.insert_slice_with_siphashes13(&values);
+    ///
+    /// let mut sequential = MinHash::<u64, 128>::new();
+    /// for value in values.iter() {
+    ///     sequential.insert_with_siphashes13(value);
+    /// }
+    ///
+    /// assert_eq!(batch, sequential);
+    /// ```
+    pub fn insert_slice_with_siphashes13<H: Hash>(&mut self, values: &[H]) {
+        const BLOCK: usize = 64;
+        let mut seeds = [0_u64; BLOCK];
+        for block in values.chunks(BLOCK) {
+            for (seed, value) in seeds.iter_mut().zip(block.iter()) {
+                *seed = Self::iter_siphashes13_from_value(value).state();
+            }
+            let seeds = &seeds[..block.len()];
+            for (permutation, word) in self.words.iter_mut().enumerate() {
+                let mut minimum = *word;
+                for seed in seeds.iter() {
+                    minimum.set_min(narrow(permutation_hash(*seed, permutation)));
+                }
+                *word = minimum;
+            }
+        }
+    }
+
+    /// Insert all the shingles of length `k` of the provided bytes into the MinHash using the SipHasher13.
+    ///
+    /// # Arguments
+    /// * `data` - The bytes to shingle.
+    /// * `k` - The length of the shingles.
+    ///
+    /// # Implementative details
+    /// Each window of `k` bytes is hashed exactly once, as the slice `&data[i..i + k]`,
+    /// and the words are then derived from that single hash, so that the signature is
+    /// the same as the one obtained inserting each window with
+    /// [`MinHash::insert_with_siphashes13`]. The SipHasher13 is not a rolling hash, so
+    /// the cost remains proportional to `k` times the number of windows. When the
+    /// data is shorter than `k`, no shingle is inserted.
+    ///
+    /// # Panics
+    /// If `k` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut shingled = MinHash::<u64, 128>::new();
+    /// shingled.insert_shingles(b"the quick brown fox", 3);
+    ///
+    /// let mut manual = MinHash::<u64, 128>::new();
+    /// for window in b"the quick brown fox".windows(3) {
+    ///     manual.insert_with_siphashes13(window);
+    /// }
+    ///
+    /// assert_eq!(shingled, manual);
+    /// ```
+    pub fn insert_shingles(&mut self, data: &[u8], k: usize) {
+        assert!(k > 0, "The length of the shingles must be positive.");
+        for window in data.windows(k) {
+            self.insert_prehashed(hash_value(window, SipHasher13::new()));
+        }
+    }
+
+    /// Insert a value into the MinHash using the SipHasher13, returning the number of words it won.
+    ///
+    /// # Arguments
+    /// * `value` - The value to insert.
+    ///
+    /// # Returns
+    /// The number of permutations for which the value set a new, strictly smaller, minimum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut minhash = MinHash::<u64, 128>::new();
+    ///
+    /// assert_eq!(minhash.insert_returning_wins_with_siphashes13(42), 128);
+    /// assert_eq!(minhash.insert_returning_wins_with_siphashes13(42), 0);
+    ///
+    /// let wins = minhash.insert_returning_wins_with_siphashes13(47);
+    /// assert!(wins > 0 && wins < 128);
+    ///
+    /// let mut saturated = MinHash::<u8, 16>::from_words([0; 16]);
+    ///
+    /// assert_eq!(saturated.insert_returning_wins_with_siphashes13(42), 0);
+    /// ```
+    pub fn insert_returning_wins_with_siphashes13<H: Hash>(&mut self, value: H) -> usize {
+        let mut wins = 0;
+        for (word, hash) in self
+            .iter_mut()
+            .zip(Self::iter_siphashes13_from_value(value))
+        {
+            if !word.is_min(hash) {
+                word.set_min(hash);
+                wins += 1;
+            }
+        }
+        wins
+    }
+
+    /// Insert a value into the first `k` words of the MinHash using the SipHasher13.
+    ///
+    /// # Arguments
+    /// * `value` - The value to insert.
+    /// * `k` - The number of permutations to update.
+    ///
+    /// # Implementative details
+    /// Only the first `k` words are updated, while the remaining ones are left
+    /// untouched. This allows to experiment with different effective numbers of
+    /// permutations on the same allocation, as long as the comparison is then
+    /// executed with [`MinHash::estimate_jaccard_on_prefix`] using the same `k`.
+    ///
+    /// # Panics
+    /// If `k` is larger than `PERMUTATIONS`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut minhash = MinHash::<u64, 128>::new();
+    /// let mut reference = MinHash::<u64, 128>::new();
+    ///
+    /// minhash.insert_with_first_k_with_siphashes13(42, 32);
+    /// reference.insert_with_siphashes13(42);
+    ///
+    /// assert_eq!(&minhash.as_ref()[..32], &reference.as_ref()[..32]);
+    /// assert!(minhash.iter().skip(32).all(|word| *word == u64::MAX));
+    /// ```
+    pub fn insert_with_first_k_with_siphashes13<H: Hash>(&mut self, value: H, k: usize) {
+        assert!(
+            k <= PERMUTATIONS,
+            "The number of permutations to update ({}) must be smaller or equal to the number of permutations ({}).",
+            k,
+            PERMUTATIONS
+        );
+        for (word, hash) in self
+            .iter_mut()
+            .zip(Self::iter_siphashes13_from_value(value))
+            .take(k)
+        {
+            word.set_min(hash);
+        }
+    }
+
+    /// Returns whether the MinHash may contain the provided value, using the keyed SipHasher13.
+    ///
+    /// # Arguments
+    /// * `value` - The value to check.
+    /// * `key0` - The first key.
+    /// * `key1` - The second key.
+    ///
+    /// # Implementative details
+    /// The procedure estimates whether the provided value is contained
+    /// in the current MinHash data structure by checking whether all of
+    /// the words are smaller or equal to all of the hash values that
+    /// are calculated using the provided value as seed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut minhash = MinHash::<u64, 128>::new();
+    /// let key0 = 0x0123456789ABCDEF;
+    /// let key1 = 0xFEDCBA9876543210;
+    ///
+    /// assert!(!minhash.may_contain_value_with_keyed_siphashes13(42, key0, key1));
+    /// minhash.insert_with_keyed_siphashes13(42, key0, key1);
+    /// assert!(minhash.may_contain_value_with_keyed_siphashes13(42, key0, key1));
+    /// minhash.insert_with_keyed_siphashes13(47, key0, key1);
+    /// assert!(minhash.may_contain_value_with_keyed_siphashes13(47, key0, key1));
+    /// ```
+    ///
+    pub fn may_contain_value_with_keyed_siphashes13<H: Hash>(
+        &self,
+        value: H,
+        key0: u64,
+        key1: u64,
+    ) -> bool {
+        self.iter()
+            .zip(Self::iter_keyed_siphashes13_from_value(value, key0, key1))
+            .all(|(word, hash)| word.is_min(hash))
+    }
+
+    /// Insert a value into the MinHash using the keyed SipHasher13.
+    ///
+    /// # Arguments
+    /// * `value` - The value to insert.
+    /// * `key0` - The first key.
+    /// * `key1` - The second key.
+    ///
+    /// # Examples
+    /// In the following example we show how we can
+    /// create a MinHash and insert a value in it.
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut minhash = MinHash::<u64, 128>::new();
+    /// let key0 = 0x0123456789ABCDEF;
+    /// let key1 = 0xFEDCBA9876543210;
+    ///
+    /// assert!(!minhash.may_contain_value_with_keyed_siphashes13(42, key0, key1));
+    /// minhash.insert_with_keyed_siphashes13(42, key0, key1);
+    /// assert!(minhash.may_contain_value_with_keyed_siphashes13(42, key0, key1));
+    /// minhash.insert_with_keyed_siphashes13(47, key0, key1);
+    /// assert!(minhash.may_contain_value_with_keyed_siphashes13(47, key0, key1));
+    /// ```
+    pub fn insert_with_keyed_siphashes13<H: Hash>(&mut self, value: H, key0: u64, key1: u64) {
+        self.insert_prehashed(hash_value(value, SipHasher13::new_with_keys(key0, key1)));
+    }
+
+    /// Insert a value into the MinHash using `K` independently keyed SipHasher13.
+    ///
+    /// # Arguments
+    /// * `value` - The value to insert.
+    ///
+    /// # Implementative details
+    /// The permutations are split into `K` contiguous groups of `PERMUTATIONS / K`
+    /// words. The `g`-th group is seeded by the SipHasher13 with both keys equal to
+    /// `g * 0x9e3779b97f4a7c15`, so that the first group uses the same unkeyed hasher
+    /// of [`MinHash::insert_with_siphashes13`] and `K = 1` matches it exactly. Within
+    /// each group, the words are derived from the seed as in the single-seed insertion.
+    ///
+    /// Each insertion costs `K` hashes of the value instead of one, while the derivation
+    /// of the words is unchanged. Since the SplitMix64 finalizer already makes the
+    /// permutations derived from a single seed behave as independent ones, the accuracy
+    /// is comparable: the additional seeds only guard against values whose base hashes
+    /// collide, which then differ in all of their words only if they collide in every group.
+    ///
+    /// # Compile-time checks
+    /// The number of groups `K` must be positive and divide `PERMUTATIONS`.
+    ///
+    /// ```compile_fail
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut minhash = MinHash::<u64, 128>::new();
+    /// minhash.insert_with_k_siphashes13::<3, _>(42);
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut single = MinHash::<u64, 128>::new();
+    /// let mut grouped = MinHash::<u64, 128>::new();
+    /// let mut reference = MinHash::<u64, 128>::new();
+    ///
+    /// for value in 0..1000_u64 {
+    ///     single.insert_with_k_siphashes13::<1, _>(value);
+    ///     grouped.insert_with_k_siphashes13::<4, _>(value);
+    ///     reference.insert_with_siphashes13(value);
+    /// }
+    ///
+    /// assert_eq!(single, reference);
+    /// assert_ne!(grouped, reference);
+    /// assert!(grouped.iter().take(32).eq(reference.iter().take(32)));
+    /// ```
+    pub fn insert_with_k_siphashes13<const K: usize, H: Hash>(&mut self, value: H) {
+        const {
+            assert!(K > 0, "The number of groups must be positive.");
+            assert!(
+                PERMUTATIONS.is_multiple_of(K),
+                "The number of groups must divide the number of permutations."
+            );
+        };
+        let group_size = PERMUTATIONS / K;
+        for (group, words) in self.words.chunks_exact_mut(group_size).enumerate() {
+            let key = (group as u64).wrapping_mul(GOLDEN_GAMMA);
+            let seed = hash_value(&value, SipHasher13::new_with_keys(key, key));
+            for (permutation, word) in words.iter_mut().enumerate() {
+                word.set_min(narrow(permutation_hash(
+                    seed,
+                    group * group_size + permutation,
+                )));
+            }
+        }
+    }
+
+    /// Returns whether the MinHash may contain the provided value, using the FVN.
+    ///
+    /// # Arguments
+    /// * `value` - The value to check.
+    ///
+    /// # Implementative details
+    /// The procedure estimates whether the provided value is contained
+    /// in the current MinHash data structure by checking whether all of
+    /// the words are smaller or equal to all of the hash values that
+    /// are calculated using the provided value as seed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut minhash = MinHash::<u64, 128>::new();
+    ///
+    /// assert!(!minhash.may_contain_value_with_fvn(42));
+    /// minhash.insert_with_fvn(42);
+    /// assert!(minhash.may_contain_value_with_fvn(42));
+    /// minhash.insert_with_fvn(47);
+    /// assert!(minhash.may_contain_value_with_fvn(47));
+    /// ```
+    ///
+    pub fn may_contain_value_with_fvn<H: Hash>(&self, value: H) -> bool {
+        self.iter()
+            .zip(Self::iter_fvn_from_value(value))
+            .all(|(word, hash)| word.is_min(hash))
+    }
+
+    /// Insert a value into the MinHash using the FVN.
+    ///
+    /// # Arguments
+    /// * `value` - The value to insert.
+    ///
+    /// # Examples
+    /// In the following example we show how we can
+    /// create a MinHash and insert a value in it.
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut minhash = MinHash::<u64, 128>::new();
+    ///
+    /// assert!(!minhash.may_contain_value_with_fvn(42));
+    /// minhash.insert_with_fvn(42);
+    /// assert!(minhash.may_contain_value_with_fvn(42));
+    /// minhash.insert_with_fvn(47);
+    /// assert!(minhash.may_contain_value_with_fvn(47));
+    /// ```
+    pub fn insert_with_fvn<H: Hash>(&mut self, value: H) {
+        self.insert_prehashed(hash_value(value, FnvHasher::default()));
+    }
+
+    /// Returns whether the MinHash may contain the provided value, using the XxHash64.
+    ///
+    /// # Arguments
+    /// * `value` - The value to check.
+    ///
+    /// # Implementative details
+    /// The procedure estimates whether the provided value is contained
+    /// in the current MinHash data structure by checking whether all of
+    /// the words are smaller or equal to all of the hash values that
+    /// are calculated using the provided value as seed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut minhash = MinHash::<u64, 128>::new();
+    ///
+    /// assert!(!minhash.may_contain_value_with_xxhash64(42));
+    /// minhash.insert_with_xxhash64(42);
+    /// assert!(minhash.may_contain_value_with_xxhash64(42));
+    /// ```
+    #[cfg(feature = "xxhash")]
+    pub fn may_contain_value_with_xxhash64<H: Hash>(&self, value: H) -> bool {
+        self.iter()
+            .zip(Self::iter_xxhash64_from_value(value))
+            .all(|(word, hash)| word.is_min(hash))
+    }
+
+    /// Insert a value into the MinHash using the XxHash64.
+    ///
+    /// # Arguments
+    /// * `value` - The value to insert.
+    ///
+    /// # Implementative details
+    /// To reproduce the words in another language, for each value:
+    ///
+    /// 1. The value is fed to the XxHash64 with seed `0` through its [`Hash`]
+    ///    implementation, and `seed` is its 64-bit digest. An `u64` is fed as its
+    ///    8 bytes in native (on most platforms little-endian) order, while a `str`
+    ///    is fed as its bytes followed by the byte `0xff`. To hash arbitrary
+    ///    bytes on both sides, compute the digest externally and use [`MinHash::insert_prehashed`].
+    /// 2. For the permutation `i`, starting from zero, the state is
+    ///    `state = seed + (i + 1) * 0x9e3779b97f4a7c15` modulo `2^64`.
+    /// 3. The state is mixed with the SplitMix64 finalizer:
+    ///    `z = (state ^ (state >> 30)) * 0xbf58476d1ce4e5b9`,
+    ///    `z = (z ^ (z >> 27)) * 0x94d049bb133111eb`, `hash = z ^ (z >> 31)`,
+    ///    where the products are modulo `2^64`.
+    /// 4. Words narrower than 64 bits keep the highest bits of the hash, while
+    ///    `u128` words keep the hash in their highest 64 bits and the hash mixed
+    ///    once more with the SplitMix64 finalizer in their lowest 64 bits.
+    /// 5. The word of the permutation `i` is the minimum of its current value and of the hash.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut minhash = MinHash::<u64, 4>::new();
+    ///
+    /// minhash.insert_with_xxhash64(42_u64);
+    ///
+    /// // A fixed test vector, guarding against changes of the algorithm.
+    /// assert_eq!(
+    ///     minhash.into_words(),
+    ///     [
+    ///         0x4206f5ea5df8f4d1,
+    ///         0xb08c65aaef39a293,
+    ///         0xa926a992531bf019,
+    ///         0x8dab991b44d99818,
+    ///     ]
+    /// );
+    /// ```
+    #[cfg(feature = "xxhash")]
+    pub fn insert_with_xxhash64<H: Hash>(&mut self, value: H) {
+        self.insert_prehashed(hash_value(value, twox_hash::XxHash64::with_seed(0)));
+    }
+
+    /// Returns whether the MinHash may contain the provided value, using the keyed FVN.
+    ///
+    /// # Arguments
+    /// * `value` - The value to check.
+    /// * `key` - The first key.
+    ///
+    /// # Implementative details
+    /// The procedure estimates whether the provided value is contained
+    /// in the current MinHash data structure by checking whether all of
+    /// the words are smaller or equal to all of the hash values that
+    /// are calculated using the provided value as seed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut minhash = MinHash::<u64, 128>::new();
+    /// let key = 0x0123456789ABCDEF;
+    ///
+    /// assert!(!minhash.may_contain_value_with_keyed_fvn(42, key));
+    /// minhash.insert_with_keyed_fvn(42, key);
+    /// assert!(minhash.may_contain_value_with_keyed_fvn(42, key));
+    /// minhash.insert_with_keyed_fvn(47, key);
+    /// assert!(minhash.may_contain_value_with_keyed_fvn(47, key));
+    /// ```
+    ///
+    pub fn may_contain_value_with_keyed_fvn<H: Hash>(&self, value: H, key: u64) -> bool {
+        self.iter()
+            .zip(Self::iter_keyed_fvn_from_value(value, key))
+            .all(|(word, hash)| word.is_min(hash))
+    }
+
+    /// Insert a value into the MinHash using the keyed FVN.
+    ///
+    /// # Arguments
+    /// * `value` - The value to insert.
+    /// * `key` - The first key.
+    ///
+    /// # Examples
+    /// In the following example we show how we can
+    /// create a MinHash and insert a value in it.
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut minhash = MinHash::<u64, 128>::new();
+    /// let key = 0x0123456789ABCDEF;
+    ///
+    /// assert!(!minhash.may_contain_value_with_keyed_fvn(42, key));
+    /// minhash.insert_with_keyed_fvn(42, key);
+    /// assert!(minhash.may_contain_value_with_keyed_fvn(42, key));
+    /// minhash.insert_with_keyed_fvn(47, key);
+    /// assert!(minhash.may_contain_value_with_keyed_fvn(47, key));
+    /// ```
+    pub fn insert_with_keyed_fvn<H: Hash>(&mut self, value: H, key: u64) {
+        self.insert_prehashed(hash_value(value, FnvHasher::with_key(key)));
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Word: Min + XorShift + Copy + Eq, const PERMUTATIONS: usize> MinHash<Word, PERMUTATIONS>
+where
+    Self: IterHashes<Word, PERMUTATIONS>,
+    u64: Primitive<Word>,
+{
+    /// Returns, for each of the provided values, whether the MinHash may contain it, using the SipHasher13.
+    ///
+    /// # Arguments
+    /// * `values` - The values to check.
+    ///
+    /// # Implementative details
+    /// Each value is hashed once, and its check stops at the first permutation whose word
+    /// is larger than the hash of the value, as in [`MinHash::may_contain_value_with_siphashes13`].
+    /// The cost of the check is dominated by the computation of the hashes of the permutations,
+    /// and not by their comparison with the words: comparing blocks of permutations without
+    /// branches, so that the comparisons can be vectorized, turned out to be slower, as it
+    /// computes hashes that the early exit would have skipped.
+    ///
+    /// Inserted values are always reported as present. A value that was never
+    /// inserted passes the check of each permutation with probability `n / (n + 1)`,
+    /// where `n` is the number of distinct inserted values, so that the false-positive
+    /// rate is about `exp(-PERMUTATIONS / n)`. This is only small when the number of
+    /// permutations is large compared to the number of inserted values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash: MinHash<u64, 1024> = (0..100_u64).collect();
+    ///
+    /// let inserted: Vec<u64> = (0..100).collect();
+    /// let others: Vec<u64> = (100..1100).collect();
+    ///
+    /// assert!(minhash
+    ///     .may_contain_values_with_siphashes13(&inserted)
+    ///     .into_iter()
+    ///     .all(|present| present));
+    ///
+    /// // The expected false-positive rate is about exp(-1024 / 100), i.e. 0.004%.
+    /// let false_positives = minhash
+    ///     .may_contain_values_with_siphashes13(&others)
+    ///     .into_iter()
+    ///     .filter(|present| *present)
+    ///     .count();
+    ///
+    /// assert!(false_positives <= 10, "False positives: {}", false_positives);
+    /// ```
+    ///
+    /// Each result matches the check of the single value:
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash: MinHash<u32, 256> = (0..10_u64).collect();
+    ///
+    /// let values: Vec<u64> = (0..20).collect();
+    /// let expected: Vec<bool> = values
+    ///     .iter()
+    ///     .map(|value| minhash.may_contain_value_with_siphashes13(value))
+    ///     .collect();
+    ///
+    /// assert_eq!(minhash.may_contain_values_with_siphashes13(&values), expected);
+    /// ```
+    pub fn may_contain_values_with_siphashes13<H: Hash>(&self, values: &[H]) -> Vec<bool> {
+        values
+            .iter()
+            .map(|value| {
+                let hash = hash_value(value, SipHasher13::new());
+                self.words.iter().enumerate().all(|(permutation, word)| {
+                    word.is_min(narrow(permutation_hash(hash, permutation)))
+                })
+            })
+            .collect()
+    }
+}
+
+impl<Word, const PERMUTATIONS: usize> MinHash<Word, PERMUTATIONS> {
+    /// The memory required to store the MinHash in bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// const BITS: usize = MinHash::<u32, 128>::MEMORY_BITS;
+    ///
+    /// assert_eq!(BITS, 128 * 32);
+    /// ```
+    pub const MEMORY_BITS: usize = PERMUTATIONS * core::mem::size_of::<Word>() * 8;
+
+    /// The memory required to store the MinHash in bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// const BYTES: usize = MinHash::<u64, 128>::MEMORY_BYTES;
+    ///
+    /// assert_eq!(BYTES, 128 * 8);
+    /// ```
+    pub const MEMORY_BYTES: usize = PERMUTATIONS * core::mem::size_of::<Word>();
+
+    /// Returns the size of the MinHash on the stack in bytes.
+    ///
+    /// # Implementative details
+    /// The MinHash is a plain array of words, so its size is equal
+    /// to [`MinHash::MEMORY_BYTES`] as it carries no padding nor metadata.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// assert_eq!(MinHash::<u16, 64>::stack_size(), 64 * 2);
+    /// ```
+    pub const fn stack_size() -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    /// Iterate over the words.
+    pub fn iter(&self) -> impl Iterator<Item = &Word> {
+        self.words.iter()
+    }
+
+    /// Iterate over the words mutably.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Word> {
+        self.words.iter_mut()
+    }
+
+    /// Returns the number of permutations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash = MinHash::<u64, 128>::new();
+    ///
+    /// assert_eq!(minhash.number_of_permutations(), 128);
+    /// ```
+    pub fn number_of_permutations(&self) -> usize {
+        PERMUTATIONS
+    }
+
+    /// Returns memory required to store the MinHash in bits.
+    ///
+    /// # Examples
+    /// For a MinHash with 128 permutations and 64 bit words, the memory required is 128 * 64 * 8.
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash = MinHash::<u64, 128>::new();
+    ///
+    /// assert_eq!(minhash.memory(), 128 * 64);
+    /// ```
+    ///
+    /// For a MinHash with 128 permutations and 32 bit words, the memory required is 128 * 32 * 8.
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash = MinHash::<u32, 128>::new();
+    ///
+    /// assert_eq!(minhash.memory(), 128 * 32);
+    /// ```
+    ///
+    pub fn memory(&self) -> usize {
+        Self::MEMORY_BITS
+    }
+}
+
+impl<Word: Eq, const PERMUTATIONS: usize> MinHash<Word, PERMUTATIONS> {
+    /// Returns the number of permutations whose words are equal in the two MinHashes.
+    ///
+    /// # Arguments
+    /// * `other` - The other MinHash to compare to.
+    ///
+    /// # Implementative details
+    /// The number of matching words follows a binomial distribution with
+    /// `PERMUTATIONS` trials and success probability equal to the Jaccard index,
+    /// and can be used directly in hypothesis tests.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let first = MinHash::from_words([1_u8, 2, 3, 4]);
+    /// let second = MinHash::from_words([1_u8, 5, 3, 6]);
+    ///
+    /// assert_eq!(first.matching_words(&second), 2);
+    /// assert_eq!(first.matching_words(&first), 4);
+    /// ```
+    pub fn matching_words(&self, other: &Self) -> usize {
+        self.iter()
+            .zip(other.iter())
+            .filter(|(l, r)| l == r)
+            .count()
+    }
+}
+
+impl<Word: Maximal + Eq, const PERMUTATIONS: usize> MinHash<Word, PERMUTATIONS> {
+    /// Calculate the similarity between two MinHashes.
+    ///
+    /// # Arguments
+    /// * `other` - The other MinHash to compare to.
+    ///
+    /// # Implementative details
+    /// The Jaccard index of two empty sets is undefined, so that comparing two
+    /// empty MinHashes returns `f64::NAN`, while comparing an empty MinHash with
+    /// a non-empty one returns `0.0`. Otherwise, the estimate is the fraction of
+    /// the permutations whose words are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let first_set: HashSet<u64> = [1_u64, 2_u64, 3_u64, 4_u64, 5_u64, 6_u64, 7_u64, 8_u64].iter().copied().collect();
+    /// let second_set: HashSet<u64> = [5_u64, 6_u64, 7_u64, 8_u64, 9_u64, 10_u64, 11_u64, 12_u64].iter().copied().collect();
+    ///
+    /// let mut first_minhash: MinHash<u64, 128> = first_set.iter().collect();
+    /// let mut second_minhash: MinHash<u64, 128> = second_set.iter().collect();
+    ///
+    /// let approximation = first_minhash.estimate_jaccard_index(&second_minhash);
+    /// let ground_truth = first_set.intersection(&second_set).count() as f64 / first_set.union(&second_set).count() as f64;
+    ///
+    /// assert!((approximation - ground_truth).abs() < 0.01, concat!(
+    ///     "We expected the approximation to be close to the ground truth, ",
+    ///    "but got an error of {} instead. The ground truth is {} and the approximation is {}."
+    ///    ), (approximation - ground_truth).abs(), ground_truth, approximation
+    /// );
+    /// ```
+    ///
+    /// The same applies to MinHashes with 128 bit words:
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut first_minhash = MinHash::<u128, 64>::new();
+    /// let mut second_minhash = MinHash::<u128, 64>::new();
+    ///
+    /// for i in 0..1000_u64 {
+    ///     first_minhash.insert_with_siphashes13(i);
+    ///     second_minhash.insert_with_siphashes13(i + 500);
+    /// }
+    ///
+    /// let approximation = first_minhash.estimate_jaccard_index(&second_minhash);
+    /// let ground_truth = 500.0 / 1500.0;
+    ///
+    /// assert!((approximation - ground_truth).abs() < 0.15, "Approximation: {}", approximation);
+    /// assert!((first_minhash.estimate_cardinality() - 1000.0).abs() < 300.0);
+    /// ```
+    pub fn estimate_jaccard_index(&self, other: &Self) -> f64 {
+        self.jaccard_from_matching_words(other, self.matching_words(other))
+    }
+
+    /// Returns the Jaccard index corresponding to the provided number of matching words.
+    ///
+    /// # Arguments
+    /// * `other` - The other MinHash that was compared to.
+    /// * `matches` - The number of matching words of the two MinHashes.
+    ///
+    /// # Implementative details
+    /// The emptiness of the MinHashes is only checked when some words match, and
+    /// since a non-empty MinHash almost always has a non-maximal first word, the
+    /// check is usually resolved by the very first word.
+    pub(crate) fn jaccard_from_matching_words(&self, other: &Self, matches: usize) -> f64 {
+        if matches > 0 {
+            match (self.is_empty(), other.is_empty()) {
+                (true, true) => return f64::NAN,
+                (true, false) | (false, true) => return 0.0,
+                (false, false) => {}
+            }
+        }
+        matches as f64 / PERMUTATIONS as f64
+    }
+
+    /// Calculate the Jaccard distance between two MinHashes, that is one minus their similarity.
+    ///
+    /// # Arguments
+    /// * `other` - The other MinHash to compare to.
+    ///
+    /// # Implementative details
+    /// The true Jaccard distance between sets is a metric. The estimate is the fraction of
+    /// the permutations where the words of the two MinHashes differ, which is a normalized
+    /// Hamming distance between the MinHashes, and it therefore satisfies the triangle inequality
+    /// among the MinHashes, while it only approximates the distance between the underlying sets.
+    /// As for [`MinHash::estimate_jaccard_index`], the distance between two empty MinHashes
+    /// is `f64::NAN`, and the distance between an empty and a non-empty MinHash is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let first: MinHash<u64, 1024> = (0..1000_u64).collect();
+    /// let second: MinHash<u64, 1024> = (500..1500_u64).collect();
+    ///
+    /// assert!((first.estimate_jaccard_distance(&second) - 2.0 / 3.0).abs() < 0.05);
+    /// assert_eq!(first.estimate_jaccard_distance(&first), 0.0);
+    /// assert!(MinHash::<u64, 1024>::new()
+    ///     .estimate_jaccard_distance(&MinHash::new())
+    ///     .is_nan());
+    /// ```
+    pub fn estimate_jaccard_distance(&self, other: &Self) -> f64 {
+        1.0 - self.estimate_jaccard_index(other)
+    }
+
+    /// Estimate the fraction of the set of the current MinHash contained in the set of the other one.
+    ///
+    /// # Arguments
+    /// * `other` - The other MinHash to compare to.
+    /// * `self_cardinality` - The cardinality of the set of the current MinHash.
+    /// * `other_cardinality` - The cardinality of the set of the other MinHash.
+    ///
+    /// # Implementative details
+    /// The containment of `A` in `B` is `|A ∩ B| / |A|`. Since the Jaccard index is
+    /// `J = |A ∩ B| / |A ∪ B|` and `|A ∪ B| = |A| + |B| - |A ∩ B|`, we have that
+    /// `J * (|A| + |B|) = (1 + J) * |A ∩ B|`, and therefore the containment is
+    /// `J * (|A| + |B|) / ((1 + J) * |A|)`. The estimate is clamped to the interval
+    /// `[0, 1]`, and it is zero when the cardinality of the current set is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let small: MinHash<u64, 1024> = (0..1000_u64).collect();
+    /// let large: MinHash<u64, 1024> = (0..10_000_u64).collect();
+    ///
+    /// let containment = small.estimate_containment(&large, 1000.0, 10_000.0);
+    ///
+    /// assert!(small.estimate_jaccard_index(&large) < 0.15);
+    /// assert!(containment > 0.8, "Containment: {}", containment);
+    /// ```
+    pub fn estimate_containment(
+        &self,
+        other: &Self,
+        self_cardinality: f64,
+        other_cardinality: f64,
+    ) -> f64 {
+        if self_cardinality == 0.0 {
+            return 0.0;
+        }
+        let jaccard = self.estimate_jaccard_index(other);
+        (jaccard * (self_cardinality + other_cardinality) / ((1.0 + jaccard) * self_cardinality))
+            .clamp(0.0, 1.0)
+    }
+
+    /// Calculate the similarity between two MinHashes and its standard error.
+    ///
+    /// # Arguments
+    /// * `other` - The other MinHash to compare to.
+    ///
+    /// # Implementative details
+    /// The number of matching words follows a binomial distribution with
+    /// `PERMUTATIONS` trials and success probability equal to the Jaccard index,
+    /// so the standard error of the estimate `p` is `sqrt(p * (1 - p) / PERMUTATIONS)`.
+    /// Both values are computed with a single pass over the words.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let small_first: MinHash<u64, 64> = (0..1000_u64).collect();
+    /// let small_second: MinHash<u64, 64> = (500..1500_u64).collect();
+    /// let large_first: MinHash<u64, 1024> = (0..1000_u64).collect();
+    /// let large_second: MinHash<u64, 1024> = (500..1500_u64).collect();
+    ///
+    /// let (small_estimate, small_error) = small_first.estimate_jaccard_with_stderr(&small_second);
+    /// let (large_estimate, large_error) = large_first.estimate_jaccard_with_stderr(&large_second);
+    ///
+    /// assert_eq!(small_estimate, small_first.estimate_jaccard_index(&small_second));
+    /// assert_eq!(large_estimate, large_first.estimate_jaccard_index(&large_second));
+    /// assert!(large_error < small_error);
+    /// ```
+    pub fn estimate_jaccard_with_stderr(&self, other: &Self) -> (f64, f64) {
+        let estimate = self.estimate_jaccard_index(other);
+        (
+            estimate,
+            libm::sqrt(estimate * (1.0 - estimate) / PERMUTATIONS as f64),
+        )
+    }
+
+    /// Returns the confidence interval of the Jaccard index between two MinHashes.
+    ///
+    /// # Arguments
+    /// * `other` - The other MinHash to compare to.
+    /// * `z` - The quantile of the standard normal distribution, such as `1.96` for 95% confidence.
+    ///
+    /// # Implementative details
+    /// The interval is the normal approximation `p ± z * stderr`, clamped to `[0, 1]`.
+    /// Note that when the estimate is exactly `0` or `1` the standard error
+    /// is zero, and the interval collapses to a single point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let first: MinHash<u64, 128> = (0..1000_u64).collect();
+    /// let second: MinHash<u64, 128> = (500..1500_u64).collect();
+    ///
+    /// let (lower, upper) = first.jaccard_confidence_interval(&second, 1.96);
+    /// let estimate = first.estimate_jaccard_index(&second);
+    ///
+    /// assert!(lower <= estimate && estimate <= upper);
+    /// assert!(0.0 <= lower && upper <= 1.0);
+    /// ```
+    pub fn jaccard_confidence_interval(&self, other: &Self, z: f64) -> (f64, f64) {
+        let (estimate, error) = self.estimate_jaccard_with_stderr(other);
+        (
+            (estimate - z * error).clamp(0.0, 1.0),
+            (estimate + z * error).clamp(0.0, 1.0),
+        )
+    }
+
+    /// Returns the probability of observing the number of matching words of the two MinHashes.
+    ///
+    /// # Arguments
+    /// * `other` - The other MinHash to compare to.
+    /// * `hypothesized_jaccard` - The hypothesized Jaccard index of the two sets.
+    ///
+    /// # Implementative details
+    /// Under the hypothesis that the two sets have the provided Jaccard index,
+    /// the number of matching words follows a binomial distribution with
+    /// `PERMUTATIONS` trials, and this method returns its probability mass
+    /// function evaluated at the observed number of matching words. The ratio
+    /// of two such likelihoods can be used to run likelihood-ratio tests.
+    ///
+    /// # Panics
+    /// If the hypothesized Jaccard index is not in the interval `[0, 1]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let first: MinHash<u64, 128> = (0..1000_u64).collect();
+    /// let second: MinHash<u64, 128> = (500..1500_u64).collect();
+    ///
+    /// let observed = first.estimate_jaccard_index(&second);
+    /// let peak = first.observed_match_likelihood(&second, observed);
+    ///
+    /// assert!(peak > first.observed_match_likelihood(&second, observed - 0.05));
+    /// assert!(peak > first.observed_match_likelihood(&second, observed + 0.05));
+    /// assert_eq!(first.observed_match_likelihood(&first, 1.0), 1.0);
+    /// assert_eq!(first.observed_match_likelihood(&first, 0.0), 0.0);
+    /// ```
+    pub fn observed_match_likelihood(&self, other: &Self, hypothesized_jaccard: f64) -> f64 {
+        assert!(
+            (0.0..=1.0).contains(&hypothesized_jaccard),
+            "The hypothesized Jaccard index ({}) must be in the interval [0, 1].",
+            hypothesized_jaccard
+        );
+        let matches = self.matching_words(other);
+        let mismatches = PERMUTATIONS - matches;
+
+        // The logarithm of the binomial coefficient.
+        let mut log_likelihood: f64 = (1..=matches)
+            .map(|i| libm::log((mismatches + i) as f64 / i as f64))
+            .sum();
+
+        if matches > 0 {
+            log_likelihood += matches as f64 * libm::log(hypothesized_jaccard);
+        }
+        if mismatches > 0 {
+            log_likelihood += mismatches as f64 * libm::log1p(-hypothesized_jaccard);
+        }
+
+        libm::exp(log_likelihood)
+    }
+}
+
+impl<Word: Eq, const PERMUTATIONS: usize> MinHash<Word, PERMUTATIONS> {
+    /// Calculate the similarity between two MinHashes using only their first `k` words.
+    ///
+    /// # Arguments
+    /// * `other` - The other MinHash to compare to.
+    /// * `k` - The number of permutations to compare.
+    ///
+    /// # Panics
+    /// If `k` is zero or larger than `PERMUTATIONS`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut first = MinHash::<u64, 128>::new();
+    /// let mut second = MinHash::<u64, 128>::new();
+    ///
+    /// for i in 0..100_u64 {
+    ///     first.insert_with_first_k_with_siphashes13(i, 64);
+    ///     second.insert_with_first_k_with_siphashes13(i + 50, 64);
+    /// }
+    ///
+    /// let approximation = first.estimate_jaccard_on_prefix(&second, 64);
+    ///
+    /// assert!((approximation - 50.0 / 150.0).abs() < 0.2);
+    /// assert_eq!(first.estimate_jaccard_on_prefix(&first, 64), 1.0);
+    /// ```
+    pub fn estimate_jaccard_on_prefix(&self, other: &Self, k: usize) -> f64 {
+        assert!(
+            k > 0 && k <= PERMUTATIONS,
+            "The number of permutations to compare ({}) must be between 1 and the number of permutations ({}).",
+            k,
+            PERMUTATIONS
+        );
+        self.iter()
+            .zip(other.iter())
+            .take(k)
+            .map(|(l, r)| (l == r) as usize)
+            .sum::<usize>() as f64
+            / k as f64
+    }
+}
+
+/// Calculate the similarity between two MinHashes with a different number of permutations.
+///
+/// # Arguments
+/// * `a` - The first MinHash, with `P` permutations.
+/// * `b` - The second MinHash, with `Q` permutations.
+///
+/// # Implementative details
+/// The `i`-th word of a MinHash only depends on the `i`-th permutation, which is derived
+/// from the hash of the value and from `i` alone, independently of the number of permutations.
+/// Therefore, the first `min(P, Q)` words of the two MinHashes are computed with the same
+/// permutations, and they are comparable as if the larger MinHash had been built with
+/// `min(P, Q)` permutations. This assumes that both MinHashes were built with the same
+/// hasher: words obtained from different hashers are never comparable. The accuracy of
+/// the estimate is governed by the smaller number of permutations, and the additional
+/// words of the larger MinHash are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use minhash_rs::prelude::*;
+///
+/// let high_resolution: MinHash<u64, 256> = (0..1000_u64).collect();
+/// let low_resolution: MinHash<u64, 64> = (500..1500_u64).collect();
+///
+/// let approximation = jaccard_common_prefix(&high_resolution, &low_resolution);
+///
+/// assert!((approximation - 500.0 / 1500.0).abs() < 0.15);
+/// assert_eq!(
+///     approximation,
+///     jaccard_common_prefix(&low_resolution, &high_resolution)
+/// );
+/// ```
+///
+/// Comparing MinHashes without permutations does not compile:
+///
+/// ```compile_fail
+/// use minhash_rs::prelude::*;
+///
+/// let first = MinHash::<u64, 0>::new();
+/// let second = MinHash::<u64, 64>::new();
+///
+/// jaccard_common_prefix(&first, &second);
+/// ```
+pub fn jaccard_common_prefix<W: Eq, const P: usize, const Q: usize>(
+    a: &MinHash<W, P>,
+    b: &MinHash<W, Q>,
+) -> f64 {
+    const {
+        assert!(
+            P > 0 && Q > 0,
+            "Both MinHashes must have at least one permutation."
+        )
+    };
+    a.iter()
+        .zip(b.iter())
+        .map(|(l, r)| (l == r) as usize)
+        .sum::<usize>() as f64
+        / P.min(Q) as f64
+}
+
+/// Returns the standard error of the Jaccard index estimated with the provided number of permutations.
+///
+/// # Arguments
+/// * `permutations` - The number of permutations.
+/// * `jaccard` - The true Jaccard index of the two sets.
+///
+/// # Implementative details
+/// Each permutation matches with probability equal to the Jaccard index `J`, independently
+/// of the others, so the fraction of matches over `k` permutations has standard error
+/// `sqrt(J * (1 - J) / k)`, which is the one reported by [`MinHash::estimate_jaccard_with_stderr`].
+///
+/// # Panics
+/// If the number of permutations is zero, or if the Jaccard index is not between zero and one.
+///
+/// # Examples
+///
+/// ```
+/// use minhash_rs::prelude::*;
+///
+/// assert_eq!(expected_std_error(100, 0.5), 0.05);
+/// assert_eq!(expected_std_error(100, 1.0), 0.0);
+/// ```
+pub fn expected_std_error(permutations: usize, jaccard: f64) -> f64 {
+    assert!(
+        permutations > 0,
+        "The number of permutations must be positive."
+    );
+    assert!(
+        (0.0..=1.0).contains(&jaccard),
+        "The Jaccard index must be between zero and one."
+    );
+    libm::sqrt(jaccard * (1.0 - jaccard) / permutations as f64)
+}
+
+/// Returns the minimum number of permutations guaranteeing the provided standard error.
+///
+/// # Arguments
+/// * `target_std_error` - The largest acceptable standard error of the estimated Jaccard index.
+///
+/// # Implementative details
+/// The standard error `sqrt(J * (1 - J) / k)` is largest when the Jaccard index `J` is one half,
+/// where it is `1 / (2 * sqrt(k))`. The returned number of permutations `ceil(1 / (4 * target^2))`
+/// is therefore sufficient for any Jaccard index. Note that the commonly quoted `1 / sqrt(k)`
+/// bound is twice the worst case, and would require four times as many permutations.
+///
+/// # Panics
+/// If the target standard error is not positive.
+///
+/// # Examples
+///
+/// ```
+/// use minhash_rs::prelude::*;
+///
+/// assert_eq!(permutations_for_error(0.01), 2500);
+/// assert_eq!(permutations_for_error(0.5), 1);
+/// assert!(expected_std_error(permutations_for_error(0.03), 0.5) <= 0.03);
+/// ```
+pub fn permutations_for_error(target_std_error: f64) -> usize {
+    assert!(
+        target_std_error > 0.0,
+        "The target standard error must be positive."
+    );
+    (libm::ceil(0.25 / (target_std_error * target_std_error)) as usize).max(1)
+}
+
+impl<Word: Maximal + Eq, const PERMUTATIONS: usize> MinHash<Word, PERMUTATIONS> {
+    /// Calculate the similarity between two MinHashes, ignoring the words that are unset in both.
+    ///
+    /// # Arguments
+    /// * `other` - The other MinHash to compare to.
+    ///
+    /// # Implementative details
+    /// The permutations where both words are still equal to the maximal value
+    /// carry no information on the two sets, yet [`MinHash::estimate_jaccard_index`]
+    /// counts them as matches. This method only counts the permutations where at
+    /// least one of the two words is not maximal, and divides the matches by
+    /// their number. It returns `f64::NAN` when there is no such permutation,
+    /// as happens when both MinHashes are empty.
+    ///
+    /// The two estimates coincide unless some permutation is unset in both MinHashes,
+    /// which is only likely for narrow words and sets much smaller than the
+    /// number of values a word can assume. In that regime, as for instance when
+    /// comparing small sets with `u8` words, this estimate is to be preferred,
+    /// while otherwise the plain estimate is cheaper and equivalent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let first = MinHash::from_words([1_u8, 2, u8::MAX, u8::MAX]);
+    /// let second = MinHash::from_words([1_u8, 3, 4, u8::MAX]);
+    ///
+    /// assert_eq!(first.estimate_jaccard_index(&second), 0.5);
+    /// assert_eq!(first.estimate_jaccard_index_dense(&second), 1.0 / 3.0);
+    /// assert!(MinHash::<u8, 4>::new()
+    ///     .estimate_jaccard_index_dense(&MinHash::new())
+    ///     .is_nan());
+    /// ```
+    pub fn estimate_jaccard_index_dense(&self, other: &Self) -> f64 {
+        let maximal = Word::maximal();
+        let (matches, informative) = self
+            .iter()
+            .zip(other.iter())
+            .filter(|(l, r)| **l != maximal || **r != maximal)
+            .fold((0_usize, 0_usize), |(matches, informative), (l, r)| {
+                (matches + (l == r) as usize, informative + 1)
+            });
+        if informative == 0 {
+            return f64::NAN;
+        }
+        matches as f64 / informative as f64
+    }
+}
+
+/// Returns the probability that the words of two MinHashes with narrow words match under a permutation.
+///
+/// # Arguments
+/// * `space` - The number of values a word can assume.
+/// * `span` - The number of smallest values over which the probability is summed.
+/// * `first` - The cardinality of the first set.
+/// * `second` - The cardinality of the second set.
+/// * `shared` - The cardinality of the intersection of the two sets.
+///
+/// # Implementative details
+/// Denoting with `s(v) = ln(1 - v / space)`, the probability that the two words are at
+/// least `a` and `b` respectively is `exp(x s(a) + y s(b) + z s(max(a, b)))`, where `x`
+/// and `y` are the numbers of elements of each set alone and `z` the number of shared ones.
+/// The probability that both words are equal to `v` follows by inclusion-exclusion over
+/// `a, b` in `{v, v + 1}`, and the values beyond the span have negligible probability.
+fn saturated_match_probability(
+    space: f64,
+    span: usize,
+    first: f64,
+    second: f64,
+    shared: f64,
+) -> f64 {
+    let union = first + second - shared;
+    let first_only = first - shared;
+    let second_only = second - shared;
+    let mut log_current = 0.0;
+    let mut probability = 0.0;
+    for value in 1..=span {
+        let log_next = if value as f64 >= space {
+            f64::NEG_INFINITY
+        } else {
+            libm::log1p(-(value as f64) / space)
+        };
+        probability += libm::exp(union * log_current)
+            - libm::exp(first * log_next + second_only * log_current)
+            - libm::exp(first_only * log_current + second * log_next)
+            + libm::exp(union * log_next);
+        log_current = log_next;
+    }
+    probability
+}
+
+impl<Word: Maximal + Eq + Copy + Primitive<f64>, const PERMUTATIONS: usize>
+    MinHash<Word, PERMUTATIONS>
+{
+    /// Calculate the similarity between two MinHashes, correcting for the collisions of saturated words.
+    ///
+    /// # Arguments
+    /// * `other` - The other MinHash to compare to.
+    ///
+    /// # Implementative details
+    /// A narrow word can only assume `max + 1` values, so that as the sets grow their
+    /// minima collapse onto the smallest values, down to zero, and the words of two
+    /// MinHashes match by collision even when the sets are disjoint: the plain
+    /// [`MinHash::estimate_jaccard_index`] then overestimates the similarity. Ignoring the
+    /// saturated words does not remove the bias, as the collisions extend to the small
+    /// non-zero values. This method models the collisions instead: the cardinalities of
+    /// the two sets are estimated with the [`FiniteSpaceEstimator`], and the cardinality
+    /// of their intersection is found by bisection as the one whose expected fraction of
+    /// matching words, collisions included, is equal to the observed one.
+    ///
+    /// When the words are so wide that the collisions are negligible, the plain estimate
+    /// is returned. As for the plain estimate, comparing two empty MinHashes returns
+    /// `f64::NAN` and comparing an empty MinHash with a non-empty one returns `0.0`.
+    /// The cardinality of a fully saturated MinHash cannot be bounded, and comparing it
+    /// returns `f64::NAN` as well. The variance of the estimate grows with the fraction
+    /// of saturated words, and wider words are the only way to recover accurate estimates
+    /// of very large sets.
+    ///
+    /// [`FiniteSpaceEstimator`]: crate::cardinality::FiniteSpaceEstimator
+    ///
+    /// # Examples
     ///
     /// ```
     /// use minhash_rs::prelude::*;
     ///
-    /// let minhash = MinHash::<u32, 128>::new();
+    /// // Two disjoint sets, large enough to saturate most of the `u8` words.
+    /// let first: MinHash<u8, 1024> = (0..500_u64).collect();
+    /// let second: MinHash<u8, 1024> = (1_000_000..1_000_500_u64).collect();
     ///
-    /// assert_eq!(minhash.memory(), 128 * 32);
+    /// assert!(first.estimate_jaccard_index(&second) > 0.5);
+    /// assert!(first.estimate_jaccard_index_saturation_corrected(&second) < 0.1);
+    ///
+    /// assert!(MinHash::<u8, 4>::from_words([0; 4])
+    ///     .estimate_jaccard_index_saturation_corrected(&MinHash::from_words([0; 4]))
+    ///     .is_nan());
     /// ```
+    pub fn estimate_jaccard_index_saturation_corrected(&self, other: &Self) -> f64 {
+        match (self.is_empty(), other.is_empty()) {
+            (true, true) => return f64::NAN,
+            (true, false) | (false, true) => return 0.0,
+            (false, false) => {}
+        }
+        let first = self.estimate_cardinality_with::<FiniteSpaceEstimator>();
+        let second = other.estimate_cardinality_with::<FiniteSpaceEstimator>();
+        if first.is_infinite() || second.is_infinite() {
+            return f64::NAN;
+        }
+
+        // Beyond this span, the probability that the words are still at least as
+        // large is below `exp(-40)` for both sets.
+        let space = Primitive::<f64>::convert(Word::maximal()) + 1.0;
+        let span = libm::ceil(space * 40.0 / first.max(second)).min(space);
+        if span > (1 << 16) as f64 {
+            return self.estimate_jaccard_index(other);
+        }
+        let span = span as usize;
+
+        let observed = self.matching_words(other) as f64 / PERMUTATIONS as f64;
+        let mut lower = 0.0;
+        let mut upper = first.min(second);
+        if observed <= saturated_match_probability(space, span, first, second, lower) {
+            return 0.0;
+        }
+        if observed >= saturated_match_probability(space, span, first, second, upper) {
+            return upper / first.max(second);
+        }
+        for _ in 0..64 {
+            let middle = (lower + upper) / 2.0;
+            if saturated_match_probability(space, span, first, second, middle) < observed {
+                lower = middle;
+            } else {
+                upper = middle;
+            }
+        }
+        let shared = (lower + upper) / 2.0;
+        shared / (first + second - shared)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Word: Maximal + Eq, const PERMUTATIONS: usize> MinHash<Word, PERMUTATIONS> {
+    /// Returns the estimated Jaccard index between the MinHash and each of the provided ones.
     ///
-    pub fn memory(&self) -> usize {
-        PERMUTATIONS * core::mem::size_of::<Word>() * 8
+    /// # Arguments
+    /// * `others` - The MinHashes to compare to.
+    ///
+    /// # Implementative details
+    /// The words of the query MinHash are compared against each of the other
+    /// MinHashes in turn, so that they stay in cache across all the comparisons.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let query: MinHash<u64, 128> = (0..1000_u64).collect();
+    /// let others: Vec<MinHash<u64, 128>> = (0..4_u64)
+    ///     .map(|i| (i * 250..i * 250 + 1000).collect())
+    ///     .collect();
+    ///
+    /// let estimates = query.estimate_jaccard_index_many(&others);
+    ///
+    /// assert_eq!(estimates.len(), 4);
+    /// assert_eq!(estimates[0], 1.0);
+    /// for (estimate, other) in estimates.iter().zip(others.iter()) {
+    ///     assert_eq!(*estimate, query.estimate_jaccard_index(other));
+    /// }
+    /// ```
+    pub fn estimate_jaccard_index_many(&self, others: &[Self]) -> Vec<f64> {
+        others
+            .iter()
+            .map(|other| self.estimate_jaccard_index(other))
+            .collect()
+    }
+
+    /// Returns the `k` provided MinHashes most similar to the current one.
+    ///
+    /// # Arguments
+    /// * `others` - The MinHashes to compare to.
+    /// * `k` - The number of MinHashes to return.
+    ///
+    /// # Returns
+    /// The indices of the `k` most similar MinHashes and their estimated Jaccard index,
+    /// sorted by decreasing similarity. Ties are broken by the smallest index.
+    ///
+    /// # Implementative details
+    /// The candidates are kept in a binary heap bounded to `k` elements, ordered
+    /// by their number of matching words, so that no floating point comparison is needed.
+    /// As for [`MinHash::estimate_jaccard_index`], an empty MinHash has no matching words
+    /// with a non-empty one, and since the Jaccard index of two empty MinHashes is
+    /// undefined, the empty MinHashes are skipped when the current one is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let query: MinHash<u64, 128> = (0..1000_u64).collect();
+    /// let others: Vec<MinHash<u64, 128>> = [900_u64, 0, 500, 100]
+    ///     .iter()
+    ///     .map(|&offset| (offset..offset + 1000).collect())
+    ///     .collect();
+    ///
+    /// let top = query.top_k_jaccard(&others, 2);
+    ///
+    /// assert_eq!(top.len(), 2);
+    /// assert_eq!(top[0], (1, 1.0));
+    /// assert_eq!(top[1].0, 3);
+    /// ```
+    pub fn top_k_jaccard(&self, others: &[Self], k: usize) -> Vec<(usize, f64)> {
+        if k == 0 {
+            return Vec::new();
+        }
+        let mut heap = BinaryHeap::with_capacity(k + 1);
+        for (index, other) in others.iter().enumerate() {
+            let matches = self.matching_words(other);
+            let jaccard = self.jaccard_from_matching_words(other, matches);
+            if jaccard.is_nan() {
+                continue;
+            }
+            let matches = if jaccard == 0.0 { 0 } else { matches };
+            heap.push(Reverse((matches, Reverse(index))));
+            if heap.len() > k {
+                heap.pop();
+            }
+        }
+        heap.into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((matches, Reverse(index)))| (index, matches as f64 / PERMUTATIONS as f64))
+            .collect()
     }
 }
 
-impl<Word: Eq, const PERMUTATIONS: usize> MinHash<Word, PERMUTATIONS> {
-    /// Calculate the similarity between two MinHashes.
+impl<Word: BitXor<Output = Word> + Zero + Eq, const PERMUTATIONS: usize>
+    MinHash<Word, PERMUTATIONS>
+{
+    /// Returns whether the two MinHashes are definitely different.
     ///
     /// # Arguments
     /// * `other` - The other MinHash to compare to.
     ///
+    /// # Implementative details
+    /// The procedure XORs the corresponding words of the two MinHashes and
+    /// returns as soon as one of them is non-zero, i.e. the two sketches are
+    /// not identical. Note that two identical sketches may still come from
+    /// different sets, so a negative answer does not imply the sets are equal.
     ///
     /// # Examples
     ///
     /// ```
-    /// use std::collections::HashSet;
     /// use minhash_rs::prelude::*;
     ///
-    /// let first_set: HashSet<u64> = [1_u64, 2_u64, 3_u64, 4_u64, 5_u64, 6_u64, 7_u64, 8_u64].iter().copied().collect();
-    /// let second_set: HashSet<u64> = [5_u64, 6_u64, 7_u64, 8_u64, 9_u64, 10_u64, 11_u64, 12_u64].iter().copied().collect();
+    /// let first: MinHash<u64, 128> = (0..100_u64).collect();
+    /// let second: MinHash<u64, 128> = (0..100_u64).collect();
+    /// let third: MinHash<u64, 128> = (50..150_u64).collect();
     ///
-    /// let mut first_minhash: MinHash<u64, 128> = first_set.iter().collect();
-    /// let mut second_minhash: MinHash<u64, 128> = second_set.iter().collect();
+    /// assert!(!first.definitely_different(&second));
+    /// assert!(first.definitely_different(&third));
+    /// ```
+    pub fn definitely_different(&self, other: &Self) -> bool {
+        self.iter()
+            .zip(other.iter())
+            .any(|(l, r)| *l ^ *r != Word::zero())
+    }
+}
+
+impl<Word: PartialOrd, const PERMUTATIONS: usize> MinHash<Word, PERMUTATIONS> {
+    /// Returns whether the set of the current MinHash may be a subset of the set of the other one.
     ///
-    /// let approximation = first_minhash.estimate_jaccard_index(&second_minhash);
-    /// let ground_truth = first_set.intersection(&second_set).count() as f64 / first_set.union(&second_set).count() as f64;
+    /// # Arguments
+    /// * `other` - The other MinHash to compare to.
     ///
-    /// assert!((approximation - ground_truth).abs() < 0.01, concat!(
-    ///     "We expected the approximation to be close to the ground truth, ",
-    ///    "but got an error of {} instead. The ground truth is {} and the approximation is {}."
-    ///    ), (approximation - ground_truth).abs(), ground_truth, approximation
+    /// # Implementative details
+    /// The minima of a superset are smaller than or equal to the minima of
+    /// its subsets, so that the procedure checks whether each word of the other
+    /// MinHash is smaller than or equal to the corresponding word of the current one.
+    /// The test is one-sided: it never fails for a true subset, but it may succeed
+    /// for a set that is not a subset, especially when the other set is much larger.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let subset: MinHash<u64, 128> = (0..100_u64).collect();
+    /// let superset: MinHash<u64, 128> = (0..1000_u64).collect();
+    /// let disjoint: MinHash<u64, 128> = (5000..6000_u64).collect();
+    ///
+    /// assert!(subset.is_subset_of(&superset));
+    /// assert!(subset.is_subset_of(&subset));
+    /// assert!(!superset.is_subset_of(&subset));
+    /// assert!(!subset.is_subset_of(&disjoint));
+    /// ```
+    pub fn is_subset_of(&self, other: &Self) -> bool {
+        self.iter().zip(other.iter()).all(|(l, r)| r <= l)
+    }
+}
+
+impl<Word: Min + Copy, const PERMUTATIONS: usize> MinHash<Word, PERMUTATIONS> {
+    /// Write the union of the MinHash and the provided one into the output MinHash.
+    ///
+    /// # Arguments
+    /// * `other` - The other MinHash to merge.
+    /// * `out` - The MinHash to write the union into, whose previous words are overwritten.
+    ///
+    /// # Implementative details
+    /// Each word of the output is the minimum of the words of the two MinHashes,
+    /// as in the union computed by `|`, but neither of the inputs is copied nor
+    /// modified, so that the output can be a preallocated slot of a reduction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let first: MinHash<u64, 128> = (0..1000_u64).collect();
+    /// let second: MinHash<u64, 128> = (500..1500_u64).collect();
+    /// let mut union = MinHash::new();
+    ///
+    /// first.merge_into(&second, &mut union);
+    ///
+    /// assert_eq!(union, (0..1500_u64).collect());
+    /// ```
+    pub fn merge_into(&self, other: &Self, out: &mut Self) {
+        for ((out, left), right) in out.words.iter_mut().zip(self.iter()).zip(other.iter()) {
+            *out = *left;
+            out.set_min(*right);
+        }
+    }
+
+    /// Returns a MinHash with half the permutations, folding the second half of the words onto the first.
+    ///
+    /// # Implementative details
+    /// Each word of the result is the minimum of the words `i` and `i + HALF`,
+    /// which is the minimum of the set under the combination of the two hash
+    /// functions. Since the argmin of the combined hash is still uniformly
+    /// distributed over the elements of the set, the result is a valid MinHash,
+    /// whose matching words estimate the Jaccard index, with the precision of `HALF`
+    /// permutations. The folded MinHashes should only be compared among themselves:
+    /// inserting values into them, or comparing them with MinHashes built directly
+    /// with `HALF` permutations, is not meaningful.
+    ///
+    /// The number of permutations `HALF` must be half of `PERMUTATIONS`, which
+    /// is checked at compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let first: MinHash<u64, 256> = (0..1000_u64).collect();
+    /// let second: MinHash<u64, 256> = (500..1500_u64).collect();
+    ///
+    /// let folded_first = first.fold_halve::<128>();
+    /// let folded_second = second.fold_halve::<128>();
+    ///
+    /// assert_eq!(folded_first[0], first[0].min(first[128]));
+    /// assert_eq!(folded_first.number_of_permutations(), 128);
+    ///
+    /// let estimate = folded_first.estimate_jaccard_index(&folded_second);
+    ///
+    /// assert!((estimate - 1.0 / 3.0).abs() < 0.15, "Estimate: {}", estimate);
+    /// ```
+    ///
+    /// A size which is not half the number of permutations does not compile:
+    ///
+    /// ```compile_fail
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash: MinHash<u64, 256> = (0..1000_u64).collect();
+    /// let folded = minhash.fold_halve::<100>();
+    /// ```
+    pub fn fold_halve<const HALF: usize>(&self) -> MinHash<Word, HALF> {
+        const {
+            assert!(
+                HALF * 2 == PERMUTATIONS,
+                "The number of permutations of the result must be half of the original one."
+            )
+        };
+        MinHash::from_words(core::array::from_fn(|i| {
+            let mut word = self.words[i];
+            word.set_min(self.words[i + HALF]);
+            word
+        }))
+    }
+}
+
+impl<Word: Copy, const PERMUTATIONS: usize> MinHash<Word, PERMUTATIONS> {
+    /// Returns a MinHash with the first `K` words of the current one.
+    ///
+    /// # Implementative details
+    /// The permutations are independent, and the `i`-th permutation of a value
+    /// does not depend on the number of permutations, so that the first `K` words
+    /// are exactly the MinHash with `K` permutations of the same set. The truncated
+    /// MinHash can therefore be compared with, and receive the same insertions of,
+    /// MinHashes built directly with `K` permutations, but Jaccard comparisons
+    /// still require both MinHashes to have the same number of permutations.
+    ///
+    /// The number of permutations `K` must not exceed `PERMUTATIONS`, which is checked at compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let large: MinHash<u64, 2048> = (0..1000_u64).collect();
+    /// let small: MinHash<u64, 128> = (0..1000_u64).collect();
+    ///
+    /// assert_eq!(large.truncate::<128>(), small);
+    /// ```
+    ///
+    /// Truncating to more permutations does not compile:
+    ///
+    /// ```compile_fail
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash: MinHash<u64, 128> = (0..1000_u64).collect();
+    /// let truncated = minhash.truncate::<256>();
+    /// ```
+    pub fn truncate<const K: usize>(&self) -> MinHash<Word, K> {
+        const {
+            assert!(
+                K <= PERMUTATIONS,
+                "The number of permutations of the result must not exceed the original one."
+            )
+        };
+        MinHash::from_words(core::array::from_fn(|i| self.words[i]))
+    }
+
+    /// Returns a MinHash with one every `STEP` words of the current one.
+    ///
+    /// # Implementative details
+    /// The result keeps the words at the positions `0, STEP, 2 * STEP, ...`,
+    /// which are independent permutations, so that the result is a MinHash
+    /// with `K` permutations and proportionally lower precision. Subsampled
+    /// MinHashes should only be compared with MinHashes subsampled with the
+    /// same step, and values should not be inserted into them.
+    ///
+    /// The step must be positive and `K` must be equal to `PERMUTATIONS / STEP`,
+    /// rounded up, which is checked at compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let first: MinHash<u64, 256> = (0..1000_u64).collect();
+    /// let second: MinHash<u64, 256> = (500..1500_u64).collect();
+    ///
+    /// let subsampled_first = first.subsample::<4, 64>();
+    /// let subsampled_second = second.subsample::<4, 64>();
+    ///
+    /// assert_eq!(subsampled_first[1], first[4]);
+    /// assert_eq!(subsampled_first[63], first[252]);
+    /// assert_eq!(first.subsample::<3, 86>()[85], first[255]);
+    ///
+    /// let estimate = subsampled_first.estimate_jaccard_index(&subsampled_second);
+    ///
+    /// assert!((estimate - 1.0 / 3.0).abs() < 0.2, "Estimate: {}", estimate);
+    /// ```
+    ///
+    /// A size which does not match the step does not compile:
+    ///
+    /// ```compile_fail
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash: MinHash<u64, 256> = (0..1000_u64).collect();
+    /// let subsampled = minhash.subsample::<4, 32>();
+    /// ```
+    pub fn subsample<const STEP: usize, const K: usize>(&self) -> MinHash<Word, K> {
+        const {
+            assert!(STEP > 0, "The step must be positive.");
+            assert!(
+                K == PERMUTATIONS.div_ceil(STEP),
+                "The number of permutations of the result must be the original one divided by the step."
+            )
+        };
+        MinHash::from_words(core::array::from_fn(|i| self.words[i * STEP]))
+    }
+
+    /// Merge the provided MinHash into the current one, replacing a random fraction of the words.
+    ///
+    /// # Arguments
+    /// * `other` - The more recent MinHash to merge.
+    /// * `decay` - The probability, between zero and one, of replacing each word.
+    /// * `seed` - The seed of the random choice of the words to replace.
+    ///
+    /// # Implementative details
+    /// Each word is independently replaced by the corresponding word of the other
+    /// MinHash with probability `decay`, using a SplitMix64 sequence started from
+    /// the provided seed, so that the merge is reproducible. Repeatedly merging
+    /// the MinHashes of successive time windows makes the contribution of each
+    /// window decay exponentially, as a word survives `t` merges with probability
+    /// `(1 - decay)^t`: the result approximates a MinHash of the recent elements,
+    /// weighted by their recency. Differently from the union, the result is
+    /// stochastic, and it is not the MinHash of any specific set.
+    ///
+    /// # Panics
+    /// If the decay is not between zero and one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let old: MinHash<u64, 128> = (0..1000_u64).collect();
+    /// let recent: MinHash<u64, 128> = (5000..6000_u64).collect();
+    ///
+    /// let mut unchanged = old;
+    /// unchanged.merge_with_decay(&recent, 0.0, 42);
+    /// assert_eq!(unchanged, old);
+    ///
+    /// let mut adopted = old;
+    /// adopted.merge_with_decay(&recent, 1.0, 42);
+    /// assert_eq!(adopted, recent);
+    ///
+    /// let mut decayed = old;
+    /// decayed.merge_with_decay(&recent, 0.5, 42);
+    /// let replaced = decayed.iter().zip(recent.iter()).filter(|(l, r)| l == r).count();
+    /// assert!(replaced > 32 && replaced < 96, "Replaced: {}", replaced);
+    ///
+    /// let mut reproduced = old;
+    /// reproduced.merge_with_decay(&recent, 0.5, 42);
+    /// assert_eq!(reproduced, decayed);
+    /// ```
+    pub fn merge_with_decay(&mut self, other: &Self, decay: f64, seed: u64) {
+        assert!(
+            (0.0..=1.0).contains(&decay),
+            "The decay ({}) must be between zero and one.",
+            decay
+        );
+        let mut state = seed;
+        for (word, other) in self.words.iter_mut().zip(other.words.iter()) {
+            state = state.wrapping_add(GOLDEN_GAMMA);
+            // The 53 highest bits are mapped to an uniform value in [0, 1).
+            let uniform = (state.splitmix() >> 11) as f64 / (1_u64 << 53) as f64;
+            if uniform < decay {
+                *word = *other;
+            }
+        }
+    }
+}
+
+impl<Word: Copy + Primitive<u64>, const PERMUTATIONS: usize> MinHash<Word, PERMUTATIONS> {
+    /// Returns a short identifier of the words of the MinHash.
+    ///
+    /// # Implementative details
+    /// The words are folded into a single `u64` with the SplitMix64 finalizer,
+    /// so that equal MinHashes always have the same fingerprint, while different
+    /// MinHashes have different fingerprints with high probability. Words wider
+    /// than 64 bits are folded on their highest 64 bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let first: MinHash<u64, 128> = (0..1000_u64).collect();
+    /// let second: MinHash<u64, 128> = (0..1000_u64).collect();
+    /// let third: MinHash<u64, 128> = (500..1500_u64).collect();
+    ///
+    /// assert_eq!(first.fingerprint(), second.fingerprint());
+    /// assert_ne!(first.fingerprint(), third.fingerprint());
+    /// ```
+    pub fn fingerprint(&self) -> u64 {
+        self.words.iter().fold(0, |state: u64, word| {
+            (state ^ Primitive::<u64>::convert(*word))
+                .wrapping_add(GOLDEN_GAMMA)
+                .splitmix()
+        })
+    }
+
+    /// Calculate the similarity between two MinHashes with different word types.
+    ///
+    /// # Arguments
+    /// * `other` - The other MinHash to compare to.
+    ///
+    /// # Implementative details
+    /// The words narrower than 64 bits are the highest bits of the 64-bit hashes,
+    /// so that the words of a narrower MinHash are the highest bits of the words of
+    /// a wider MinHash built from the same set with the same hasher. The words of
+    /// both MinHashes are therefore compared on their common highest bits, i.e. the
+    /// wider MinHash is narrowed to the width of the other one. The two MinHashes must
+    /// have been built with the same hasher and keys, and the estimate has the
+    /// accuracy of the narrower word type, whose collisions inflate the similarity.
+    /// Words wider than 64 bits are compared on their highest 64 bits, which for the
+    /// MinHashes built by this crate are equal to the 64-bit hashes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let legacy: MinHash<u32, 128> = (0..1000_u64).collect();
+    /// let wide: MinHash<u64, 128> = (0..1000_u64).collect();
+    /// let other: MinHash<u64, 128> = (500..1500_u64).collect();
+    /// let narrow_other: MinHash<u32, 128> = (500..1500_u64).collect();
+    /// let widest: MinHash<u128, 128> = (0..1000_u64).collect();
+    ///
+    /// assert_eq!(legacy.estimate_jaccard_index_across_words(&wide), 1.0);
+    /// assert_eq!(widest.estimate_jaccard_index_across_words(&wide), 1.0);
+    /// assert_eq!(wide.estimate_jaccard_index_across_words(&legacy), 1.0);
+    /// assert_eq!(
+    ///     legacy.estimate_jaccard_index_across_words(&other),
+    ///     legacy.estimate_jaccard_index(&narrow_other)
     /// );
     /// ```
-    pub fn estimate_jaccard_index(&self, other: &Self) -> f64 {
-        self.iter()
-            .zip(other.iter())
-            .map(|(l, r)| (l == r) as usize)
-            .sum::<usize>() as f64
+    pub fn estimate_jaccard_index_across_words<OtherWord: Copy + Primitive<u64>>(
+        &self,
+        other: &MinHash<OtherWord, PERMUTATIONS>,
+    ) -> f64 {
+        let self_bits = (8 * core::mem::size_of::<Word>()).min(64);
+        let other_bits = (8 * core::mem::size_of::<OtherWord>()).min(64);
+        let common_bits = self_bits.min(other_bits);
+        self.words
+            .iter()
+            .zip(other.words.iter())
+            .filter(|(left, right)| {
+                Primitive::<u64>::convert(**left) >> (self_bits - common_bits)
+                    == Primitive::<u64>::convert(**right) >> (other_bits - common_bits)
+            })
+            .count() as f64
             / PERMUTATIONS as f64
     }
 }
 
+impl<Word: core::fmt::LowerHex, const PERMUTATIONS: usize> core::fmt::Display
+    for MinHash<Word, PERMUTATIONS>
+{
+    /// Formats the MinHash as the concatenation of the zero-padded hexadecimal representation of its words.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash = MinHash::<u8, 4>::from_words([0, 1, 171, 255]);
+    ///
+    /// assert_eq!(minhash.to_string(), "0001abff");
+    /// assert_eq!(MinHash::<u16, 2>::from_words([10, 4096]).to_string(), "000a1000");
+    /// ```
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let width = 2 * core::mem::size_of::<Word>();
+        for word in self.words.iter() {
+            write!(f, "{:0width$x}", word, width = width)?;
+        }
+        Ok(())
+    }
+}
+
 /// We also implement AsRef and AsMut for direct access on the MinHash words.
 impl<Word, const PERMUTATIONS: usize> AsRef<[Word]> for MinHash<Word, PERMUTATIONS> {
     fn as_ref(&self) -> &[Word] {
@@ -462,6 +2938,127 @@ impl<Word, const PERMUTATIONS: usize> AsMut<[Word]> for MinHash<Word, PERMUTATIO
     }
 }
 
+/// We also provide the fallible conversions from slices and vectors of words.
+///
+/// # Examples
+///
+/// ```
+/// use minhash_rs::prelude::*;
+///
+/// let minhash: MinHash<u64, 128> = (0..1000_u64).collect();
+///
+/// assert_eq!(MinHash::try_from(minhash.as_ref()), Ok(minhash));
+/// assert_eq!(
+///     MinHash::<u64, 64>::try_from(minhash.as_ref()),
+///     Err(LengthMismatch {
+///         expected: 64,
+///         found: 128
+///     })
+/// );
+/// ```
+impl<Word: Copy, const PERMUTATIONS: usize> TryFrom<&[Word]> for MinHash<Word, PERMUTATIONS> {
+    type Error = LengthMismatch;
+
+    fn try_from(words: &[Word]) -> Result<Self, Self::Error> {
+        Self::try_from_slice(words).ok_or(LengthMismatch {
+            expected: PERMUTATIONS,
+            found: words.len(),
+        })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Word, const PERMUTATIONS: usize> TryFrom<Vec<Word>> for MinHash<Word, PERMUTATIONS> {
+    type Error = LengthMismatch;
+
+    fn try_from(words: Vec<Word>) -> Result<Self, Self::Error> {
+        let found = words.len();
+        words
+            .try_into()
+            .map(Self::from_words)
+            .map_err(|_| LengthMismatch {
+                expected: PERMUTATIONS,
+                found,
+            })
+    }
+}
+
+/// Iterating over an owned MinHash yields its words.
+///
+/// # Examples
+///
+/// ```
+/// use minhash_rs::prelude::*;
+///
+/// let minhash = MinHash::from_words([1_u8, 2, 3, 4]);
+///
+/// assert_eq!(minhash.into_iter().map(u32::from).sum::<u32>(), 10);
+///
+/// let mut total = 0;
+/// for word in &minhash {
+///     total += *word as u32;
+/// }
+/// assert_eq!(total, 10);
+/// ```
+impl<Word, const PERMUTATIONS: usize> IntoIterator for MinHash<Word, PERMUTATIONS> {
+    type Item = Word;
+    type IntoIter = core::array::IntoIter<Word, PERMUTATIONS>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.words.into_iter()
+    }
+}
+
+impl<'a, Word, const PERMUTATIONS: usize> IntoIterator for &'a MinHash<Word, PERMUTATIONS> {
+    type Item = &'a Word;
+    type IntoIter = core::slice::Iter<'a, Word>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.words.iter()
+    }
+}
+
+impl<'a, Word, const PERMUTATIONS: usize> IntoIterator for &'a mut MinHash<Word, PERMUTATIONS> {
+    type Item = &'a mut Word;
+    type IntoIter = core::slice::IterMut<'a, Word>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.words.iter_mut()
+    }
+}
+
+/// MinHashes are ordered lexicographically by their words.
+///
+/// # Implementative details
+/// The order is the lexicographic order of the arrays of words, and it is
+/// purely syntactic: it has nothing to do with the similarity of the sets,
+/// and MinHashes that are close in the order may be very dissimilar. It
+/// provides a total order, so that MinHashes can be sorted deterministically
+/// or used as keys of a `BTreeMap`.
+///
+/// # Examples
+///
+/// ```
+/// use minhash_rs::prelude::*;
+///
+/// let first = MinHash::from_words([1_u8, 2, 3]);
+/// let second = MinHash::from_words([1_u8, 3, 0]);
+///
+/// assert!(first < second);
+/// assert_eq!(first.cmp(&first), core::cmp::Ordering::Equal);
+/// ```
+impl<Word: PartialOrd, const PERMUTATIONS: usize> PartialOrd for MinHash<Word, PERMUTATIONS> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.words.partial_cmp(&other.words)
+    }
+}
+
+impl<Word: Ord, const PERMUTATIONS: usize> Ord for MinHash<Word, PERMUTATIONS> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.words.cmp(&other.words)
+    }
+}
+
 /// We also provide indexing for the MinHash.
 impl<W: Maximal, const PERMUTATIONS: usize> Index<usize> for MinHash<W, PERMUTATIONS> {
     type Output = W;
@@ -1,9 +1,20 @@
-use core::ops::{Index, IndexMut};
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+use core::hash::Hash;
+use core::ops::{BitOrAssign, Index, IndexMut};
 
+use siphasher::sip128::SipHasher13;
+
+use crate::hash_iter::hash_value;
 use crate::prelude::*;
 
 #[repr(transparent)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(repr(transparent))
+)]
 pub struct MinHashArray<Word, const PERMUTATIONS: usize, const N: usize> {
     counters: [MinHash<Word, PERMUTATIONS>; N],
 }
@@ -24,6 +35,532 @@ impl<Word: Maximal, const PERMUTATIONS: usize, const N: usize> MinHashArray<Word
     }
 }
 
+impl<Word: Maximal + Eq, const PERMUTATIONS: usize, const N: usize>
+    MinHashArray<Word, PERMUTATIONS, N>
+{
+    /// Returns the matrix of the pairwise estimated Jaccard indices of the MinHashes in the array.
+    ///
+    /// # Implementative details
+    /// The matrix is symmetric and its diagonal is filled with 1.0.
+    /// Only the upper triangle is estimated, and then mirrored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut array = MinHashArray::<u64, 128, 4>::new();
+    ///
+    /// for i in 0..4 {
+    ///     array[i] = (i as u64 * 100..i as u64 * 100 + 1000).collect();
+    /// }
+    ///
+    /// let matrix = array.pairwise_jaccard();
+    ///
+    /// for i in 0..4 {
+    ///     assert_eq!(matrix[i][i], 1.0);
+    ///     for j in 0..4 {
+    ///         assert_eq!(matrix[i][j], matrix[j][i]);
+    ///         assert_eq!(matrix[i][j], array[i].estimate_jaccard_index(&array[j]));
+    ///     }
+    /// }
+    /// assert!(matrix[0][1] > matrix[0][2]);
+    /// assert!(matrix[0][2] > matrix[0][3]);
+    /// ```
+    pub fn pairwise_jaccard(&self) -> [[f64; N]; N] {
+        let mut matrix = [[1.0; N]; N];
+        for (i, minhash) in self.counters.iter().enumerate() {
+            for (j, other) in self.counters.iter().enumerate().skip(i + 1) {
+                let similarity = minhash.estimate_jaccard_index(other);
+                matrix[i][j] = similarity;
+                matrix[j][i] = similarity;
+            }
+        }
+        matrix
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<Word: Maximal + Eq + Sync, const PERMUTATIONS: usize, const N: usize>
+    MinHashArray<Word, PERMUTATIONS, N>
+{
+    /// Returns the matrix of the pairwise estimated Jaccard indices, estimating the rows in parallel.
+    ///
+    /// # Implementative details
+    /// The result is identical to the one of [`MinHashArray::pairwise_jaccard`],
+    /// but each row is estimated independently, so that rows can be processed
+    /// by different threads at the cost of estimating each pair twice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut array = MinHashArray::<u64, 128, 16>::new();
+    ///
+    /// for i in 0..16 {
+    ///     array[i] = (i as u64 * 100..i as u64 * 100 + 1000).collect();
+    /// }
+    ///
+    /// assert_eq!(array.par_pairwise_jaccard(), array.pairwise_jaccard());
+    /// ```
+    pub fn par_pairwise_jaccard(&self) -> [[f64; N]; N] {
+        use rayon::prelude::*;
+
+        let mut matrix = [[1.0; N]; N];
+        matrix.par_iter_mut().enumerate().for_each(|(i, row)| {
+            for (j, cell) in row.iter_mut().enumerate() {
+                if i != j {
+                    *cell = self.counters[i].estimate_jaccard_index(&self.counters[j]);
+                }
+            }
+        });
+        matrix
+    }
+}
+
+impl<Word, const PERMUTATIONS: usize, const N: usize> MinHashArray<Word, PERMUTATIONS, N> {
+    /// Create a new MinHashArray from the provided MinHashes.
+    ///
+    /// # Arguments
+    /// * `counters` - The MinHashes of the array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let first: MinHash<u64, 128> = (0..1000_u64).collect();
+    /// let second: MinHash<u64, 128> = (500..1500_u64).collect();
+    ///
+    /// let array = MinHashArray::from_minhashes([first, second]);
+    ///
+    /// assert_eq!(array[1], second);
+    /// assert_eq!(array.into_minhashes(), [first, second]);
+    /// ```
+    pub fn from_minhashes(counters: [MinHash<Word, PERMUTATIONS>; N]) -> Self {
+        Self { counters }
+    }
+
+    /// Returns the MinHashes of the array.
+    pub fn into_minhashes(self) -> [MinHash<Word, PERMUTATIONS>; N] {
+        self.counters
+    }
+
+    /// Iterate over the MinHashes of the array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut array = MinHashArray::<u8, 4, 3>::new();
+    ///
+    /// array[1].insert_with_siphashes13(42);
+    ///
+    /// let empty = array.iter().filter(|minhash| minhash.is_empty()).count();
+    /// let words: usize = array.iter().map(|minhash| minhash.iter().count()).sum();
+    ///
+    /// assert_eq!(empty, 2);
+    /// assert_eq!(words, 12);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &MinHash<Word, PERMUTATIONS>> {
+        self.counters.iter()
+    }
+
+    /// Iterate mutably over the MinHashes of the array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut array = MinHashArray::<u8, 4, 3>::new();
+    ///
+    /// for (i, minhash) in array.iter_mut().enumerate() {
+    ///     minhash.insert_with_siphashes13(i);
+    /// }
+    ///
+    /// assert!(array.iter().all(|minhash| !minhash.is_empty()));
+    /// ```
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut MinHash<Word, PERMUTATIONS>> {
+        self.counters.iter_mut()
+    }
+
+    /// Returns the number of MinHashes in the array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let array = MinHashArray::<u8, 4, 3>::new();
+    ///
+    /// assert_eq!(array.len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns whether the array contains no MinHashes.
+    pub fn is_empty(&self) -> bool {
+        N == 0
+    }
+}
+
+impl<Word: Maximal + Min + Eq, const PERMUTATIONS: usize, const N: usize>
+    MinHashArray<Word, PERMUTATIONS, N>
+{
+    /// Merge the provided MinHashArray into the current one.
+    ///
+    /// # Arguments
+    /// * `other` - The other MinHashArray to merge.
+    ///
+    /// # Implementative details
+    /// Each MinHash of the array is merged with the MinHash of the other array
+    /// at the same index, with the element-wise minimum of their words, so that
+    /// it becomes the MinHash of the union of the two sets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut first_shard = MinHashArray::<u64, 128, 3>::new();
+    /// let mut second_shard = MinHashArray::<u64, 128, 3>::new();
+    /// let mut full = MinHashArray::<u64, 128, 3>::new();
+    ///
+    /// for i in 0..3 {
+    ///     for value in 0..1000_u64 {
+    ///         let value = value + i as u64 * 10_000;
+    ///         if value % 2 == 0 {
+    ///             first_shard[i].insert_with_siphashes13(value);
+    ///         } else {
+    ///             second_shard[i].insert_with_siphashes13(value);
+    ///         }
+    ///         full[i].insert_with_siphashes13(value);
+    ///     }
+    /// }
+    ///
+    /// let mut merged = first_shard;
+    /// merged.merge(&second_shard);
+    /// assert_eq!(merged, full);
+    ///
+    /// let mut merged = first_shard;
+    /// merged |= second_shard;
+    /// assert_eq!(merged, full);
+    /// ```
+    pub fn merge(&mut self, other: &Self) {
+        for (minhash, other) in self.counters.iter_mut().zip(other.counters.iter()) {
+            *minhash &= other;
+        }
+    }
+
+    /// Returns the merge of all the MinHashArrays of the provided iterator.
+    ///
+    /// # Arguments
+    /// * `arrays` - The MinHashArrays to merge.
+    ///
+    /// # Implementative details
+    /// The result starts from an empty MinHashArray, so that an empty iterator
+    /// returns an empty MinHashArray.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut shards = [MinHashArray::<u64, 128, 2>::new(); 4];
+    /// let mut full = MinHashArray::<u64, 128, 2>::new();
+    ///
+    /// for value in 0..1000_u64 {
+    ///     shards[value as usize % 4][0].insert_with_siphashes13(value);
+    ///     shards[value as usize % 4][1].insert_with_siphashes13(value * 7);
+    ///     full[0].insert_with_siphashes13(value);
+    ///     full[1].insert_with_siphashes13(value * 7);
+    /// }
+    ///
+    /// assert_eq!(MinHashArray::merge_all(shards), full);
+    /// assert_eq!(
+    ///     MinHashArray::<u64, 128, 2>::merge_all([]),
+    ///     MinHashArray::new()
+    /// );
+    /// ```
+    pub fn merge_all<I: IntoIterator<Item = Self>>(arrays: I) -> Self {
+        arrays.into_iter().fold(Self::new(), |mut merged, array| {
+            merged.merge(&array);
+            merged
+        })
+    }
+}
+
+impl<Word: Min + XorShift + Copy + Eq, const PERMUTATIONS: usize, const N: usize>
+    MinHashArray<Word, PERMUTATIONS, N>
+where
+    u64: Primitive<Word>,
+{
+    /// Insert a value into the MinHash at the provided index, using the SipHasher13.
+    ///
+    /// # Arguments
+    /// * `index` - The index of the MinHash to insert the value into.
+    /// * `value` - The value to insert.
+    ///
+    /// # Panics
+    /// If the index is not smaller than the number of MinHashes in the array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut array = MinHashArray::<u64, 128, 3>::new();
+    ///
+    /// array.insert_with_siphashes13(1, 42);
+    ///
+    /// assert!(array[0].is_empty());
+    /// assert!(array[1].may_contain_value_with_siphashes13(42));
+    /// assert!(array[2].is_empty());
+    /// ```
+    ///
+    /// Inserting out of bounds panics:
+    ///
+    /// ```should_panic
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut array = MinHashArray::<u64, 128, 3>::new();
+    ///
+    /// array.insert_with_siphashes13(3, 42);
+    /// ```
+    pub fn insert_with_siphashes13<H: Hash>(&mut self, index: usize, value: H) {
+        assert!(
+            index < N,
+            "The index ({}) must be smaller than the number of MinHashes ({}).",
+            index,
+            N
+        );
+        self.counters[index].insert_with_siphashes13(value);
+    }
+
+    /// Insert a value into all the MinHashes of the array, using the SipHasher13.
+    ///
+    /// # Arguments
+    /// * `value` - The value to insert.
+    ///
+    /// # Implementative details
+    /// The value is hashed once, and its hash is inserted into every MinHash.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut array = MinHashArray::<u64, 128, 3>::new();
+    ///
+    /// array.insert_all(42);
+    ///
+    /// assert!(array
+    ///     .iter()
+    ///     .all(|minhash| minhash.may_contain_value_with_siphashes13(42)));
+    /// ```
+    pub fn insert_all<H: Hash>(&mut self, value: H) {
+        let hash = hash_value(value, SipHasher13::new());
+        for minhash in self.counters.iter_mut() {
+            minhash.insert_prehashed(hash);
+        }
+    }
+}
+
+impl<Word: Maximal + Min + Eq, const PERMUTATIONS: usize, const N: usize> BitOrAssign<&Self>
+    for MinHashArray<Word, PERMUTATIONS, N>
+{
+    fn bitor_assign(&mut self, rhs: &Self) {
+        self.merge(rhs);
+    }
+}
+
+impl<Word: Maximal + Min + Eq, const PERMUTATIONS: usize, const N: usize> BitOrAssign<Self>
+    for MinHashArray<Word, PERMUTATIONS, N>
+{
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.merge(&rhs);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Word: Maximal + Eq, const PERMUTATIONS: usize, const N: usize>
+    MinHashArray<Word, PERMUTATIONS, N>
+{
+    /// Returns the k-nearest neighbours graph of the MinHashes in the array.
+    ///
+    /// # Arguments
+    /// * `k` - The number of neighbours to keep for each MinHash.
+    ///
+    /// # Returns
+    /// For each MinHash, the indices of the `k` most similar other MinHashes
+    /// and their estimated Jaccard index, sorted by decreasing similarity.
+    /// Ties are broken by the smallest index. The Jaccard index of two empty
+    /// MinHashes is undefined, so that an empty MinHash is never a neighbour
+    /// of another empty MinHash, and may have fewer than `k` neighbours.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut array = MinHashArray::<u64, 128, 6>::new();
+    ///
+    /// for i in 0..6 {
+    ///     // Each pair (0, 1), (2, 3) and (4, 5) shares most of its elements.
+    ///     let offset = (i / 2) as u64 * 10_000 + (i % 2) as u64 * 100;
+    ///     for value in offset..offset + 1000 {
+    ///         array[i].insert_with_siphashes13(value);
+    ///     }
+    /// }
+    ///
+    /// let graph = array.knn_graph(2);
+    ///
+    /// assert_eq!(graph.len(), 6);
+    /// for (i, neighbours) in graph.iter().enumerate() {
+    ///     assert_eq!(neighbours.len(), 2);
+    ///     assert_eq!(neighbours[0].0, i ^ 1);
+    ///     assert!(neighbours[0].1 > neighbours[1].1);
+    /// }
+    /// ```
+    pub fn knn_graph(&self, k: usize) -> Vec<Vec<(usize, f64)>> {
+        self.counters
+            .iter()
+            .enumerate()
+            .map(|(i, minhash)| {
+                let mut neighbours: Vec<(usize, f64)> = self
+                    .counters
+                    .iter()
+                    .enumerate()
+                    .filter(|(j, _)| *j != i)
+                    .map(|(j, other)| (j, minhash.estimate_jaccard_index(other)))
+                    .filter(|(_, similarity)| !similarity.is_nan())
+                    .collect();
+                neighbours.sort_by(|(i, l), (j, r)| r.total_cmp(l).then(i.cmp(j)));
+                neighbours.truncate(k);
+                neighbours
+            })
+            .collect()
+    }
+
+    /// Returns the histogram of the pairwise estimated Jaccard indices of the MinHashes in the array.
+    ///
+    /// # Arguments
+    /// * `buckets` - The number of equal-width buckets dividing the interval `[0, 1]`.
+    ///
+    /// # Implementative details
+    /// Each of the `N * (N - 1) / 2` pairs is estimated and counted on the fly,
+    /// so differently from [`MinHashArray::pairwise_jaccard`] no matrix is materialized.
+    /// A Jaccard index of exactly `1.0` is counted in the last bucket. The Jaccard
+    /// index of two empty MinHashes is undefined, and such pairs are not counted.
+    ///
+    /// # Panics
+    /// If the number of buckets is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut array = MinHashArray::<u64, 128, 6>::new();
+    ///
+    /// for i in 0..6 {
+    ///     // The MinHashes (0, 1, 2) and (3, 4, 5) form two tight clusters.
+    ///     let offset = (i / 3) as u64 * 10_000 + (i % 3) as u64 * 10;
+    ///     for value in offset..offset + 1000 {
+    ///         array[i].insert_with_siphashes13(value);
+    ///     }
+    /// }
+    ///
+    /// let histogram = array.similarity_histogram(10);
+    ///
+    /// assert_eq!(histogram.len(), 10);
+    /// assert_eq!(histogram.iter().sum::<usize>(), 15);
+    /// assert_eq!(histogram[0], 9);
+    /// assert_eq!(histogram[8] + histogram[9], 6);
+    /// ```
+    pub fn similarity_histogram(&self, buckets: usize) -> Vec<usize> {
+        assert!(buckets > 0, "The number of buckets must be positive.");
+        let mut histogram = vec![0; buckets];
+        for (i, minhash) in self.counters.iter().enumerate() {
+            for other in self.counters[i + 1..].iter() {
+                let similarity = minhash.estimate_jaccard_index(other);
+                if similarity.is_nan() {
+                    continue;
+                }
+                histogram[((similarity * buckets as f64) as usize).min(buckets - 1)] += 1;
+            }
+        }
+        histogram
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Word: Maximal + Hash + Eq, const PERMUTATIONS: usize, const N: usize>
+    MinHashArray<Word, PERMUTATIONS, N>
+{
+    /// Returns the pairs of similar MinHashes between this array and the provided one.
+    ///
+    /// # Arguments
+    /// * `other` - The other MinHashArray.
+    /// * `threshold` - The minimal estimated Jaccard index of the returned pairs.
+    ///
+    /// # Returns
+    /// The triples `(self_index, other_index, jaccard)` of the pairs whose estimated
+    /// Jaccard index is at least `threshold`, sorted by `self_index` and `other_index`.
+    ///
+    /// # Implementative details
+    /// The MinHashes of the other array are indexed in a [`LshIndex`] with `BANDS` bands
+    /// of `ROWS` rows, which is then queried with the MinHashes of this array. Only the
+    /// retrieved candidates are compared, hence pairs whose similarity is well below the
+    /// LSH threshold `(1 / BANDS)^(1 / ROWS)` may be missed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut left = MinHashArray::<u64, 128, 3>::new();
+    /// let mut right = MinHashArray::<u64, 128, 4>::new();
+    ///
+    /// for i in 0..3 {
+    ///     left[i] = (i as u64 * 10_000..i as u64 * 10_000 + 1000).collect();
+    /// }
+    /// for i in 0..4 {
+    ///     right[i] = (i as u64 * 50_000 + 20..i as u64 * 50_000 + 1020).collect();
+    /// }
+    ///
+    /// let pairs = left.cross_similarity_join::<32, 4, 4>(&right, 0.8);
+    ///
+    /// assert_eq!(pairs.len(), 1);
+    /// assert_eq!((pairs[0].0, pairs[0].1), (0, 0));
+    /// assert!(pairs[0].2 >= 0.8);
+    /// ```
+    pub fn cross_similarity_join<const BANDS: usize, const ROWS: usize, const M: usize>(
+        &self,
+        other: &MinHashArray<Word, PERMUTATIONS, M>,
+        threshold: f64,
+    ) -> Vec<(usize, usize, f64)> {
+        let mut index = LshIndex::<usize, Word, PERMUTATIONS, BANDS, ROWS>::new();
+        for (j, minhash) in other.counters.iter().enumerate() {
+            index.insert(j, minhash);
+        }
+        self.counters
+            .iter()
+            .enumerate()
+            .flat_map(|(i, minhash)| {
+                index.query(minhash).into_iter().filter_map(move |j| {
+                    let similarity = minhash.estimate_jaccard_index(&other.counters[j]);
+                    (similarity >= threshold).then_some((i, j, similarity))
+                })
+            })
+            .collect()
+    }
+}
+
 /// We also provide indexing for the MinHashArray.
 impl<W: Maximal, const PERMUTATIONS: usize, const N: usize> Index<usize>
     for MinHashArray<W, PERMUTATIONS, N>
@@ -0,0 +1,75 @@
+//! Module providing an object-safe trait for MinHashes with any word type and number of permutations.
+//!
+//! # Why should I use this?
+//! The [`MinHash`] is generic over its word type and its number of permutations,
+//! so MinHashes of different configurations have different types. The [`MinHashLike`]
+//! trait allows to store them behind trait objects, such as `Box<dyn MinHashLike>`.
+//! Only MinHashes of the same type can be compared: comparing MinHashes with different
+//! word types or numbers of permutations returns `f64::NAN`.
+
+use core::any::Any;
+
+use crate::prelude::{Maximal, MinHash};
+
+/// Object-safe trait implemented by all the [`MinHash`] configurations.
+///
+/// It erases the word type and the number of permutations, so that MinHashes of
+/// different configurations can be stored together as `dyn MinHashLike`.
+pub trait MinHashLike: Any {
+    /// Returns the MinHash as a reference to `Any`, to recover its concrete type.
+    fn as_any(&self) -> &dyn Any;
+
+    /// Calculate the similarity between the MinHash and the provided one.
+    ///
+    /// # Arguments
+    /// * `other` - The other MinHash to compare to.
+    ///
+    /// # Returns
+    /// `f64::NAN` when the two MinHashes have different word types or numbers of permutations.
+    fn estimate_jaccard_index(&self, other: &dyn MinHashLike) -> f64;
+
+    /// Returns the fraction of words that are no longer maximal.
+    fn fill_fraction(&self) -> f64;
+
+    /// Returns memory required to store the MinHash in bits.
+    fn memory(&self) -> usize;
+}
+
+impl<Word: Maximal + Eq + 'static, const PERMUTATIONS: usize> MinHashLike
+    for MinHash<Word, PERMUTATIONS>
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let registry: Vec<Box<dyn MinHashLike>> = vec![
+    ///     Box::new((0..1000_u64).collect::<MinHash<u8, 128>>()),
+    ///     Box::new((0..1000_u64).collect::<MinHash<u64, 128>>()),
+    ///     Box::new((500..1500_u64).collect::<MinHash<u64, 128>>()),
+    /// ];
+    ///
+    /// assert!((registry[1].estimate_jaccard_index(registry[2].as_ref()) - 1.0 / 3.0).abs() < 0.1);
+    /// assert!(registry[0].estimate_jaccard_index(registry[1].as_ref()).is_nan());
+    /// ```
+    fn estimate_jaccard_index(&self, other: &dyn MinHashLike) -> f64 {
+        other
+            .as_any()
+            .downcast_ref::<Self>()
+            .map_or(f64::NAN, |other| {
+                MinHash::estimate_jaccard_index(self, other)
+            })
+    }
+
+    fn fill_fraction(&self) -> f64 {
+        MinHash::fill_fraction(self)
+    }
+
+    fn memory(&self) -> usize {
+        MinHash::memory(self)
+    }
+}
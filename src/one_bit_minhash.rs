@@ -0,0 +1,137 @@
+//! Module providing the 1-bit MinHash data structure.
+//!
+//! # What is a 1-bit MinHash?
+//! A 1-bit MinHash is the special case of the [`BBitMinHash`](crate::prelude::BBitMinHash)
+//! keeping only the lowest bit of each of the words of a MinHash. The bits are packed
+//! into an array of `WORDS` 64-bit words, so that it requires no allocation and the
+//! agreeing positions of two sketches are counted with a XOR and a popcount per word.
+//! Two different minima share their lowest bit with probability one half, so the
+//! Jaccard index is estimated as `2 * agreement - 1`.
+//!
+//! # Why two const generics?
+//! Stable Rust cannot express `PERMUTATIONS / 64` in a type, so the number of
+//! 64-bit words is provided as the additional const generic `WORDS`, which must
+//! be equal to `PERMUTATIONS / 64`. This is checked at compile time.
+
+use crate::prelude::{MinHash, Primitive};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OneBitMinHash<const PERMUTATIONS: usize, const WORDS: usize> {
+    bits: [u64; WORDS],
+}
+
+impl<const PERMUTATIONS: usize, const WORDS: usize> OneBitMinHash<PERMUTATIONS, WORDS> {
+    /// Returns the number of permutations.
+    pub fn number_of_permutations(&self) -> usize {
+        PERMUTATIONS
+    }
+
+    /// Returns memory required to store the 1-bit MinHash in bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash: MinHash<u64, 1024> = (0..1000_u64).collect();
+    /// let one_bit = OneBitMinHash::<1024, 16>::from(&minhash);
+    ///
+    /// assert_eq!(one_bit.memory(), 1024);
+    /// assert_eq!(core::mem::size_of_val(&one_bit), 1024 / 8);
+    /// ```
+    pub fn memory(&self) -> usize {
+        WORDS * 64
+    }
+
+    /// Returns the packed bits of the 1-bit MinHash.
+    pub fn as_bits(&self) -> &[u64; WORDS] {
+        &self.bits
+    }
+
+    /// Calculate the similarity between two 1-bit MinHashes.
+    ///
+    /// # Arguments
+    /// * `other` - The other 1-bit MinHash to compare to.
+    ///
+    /// # Implementative details
+    /// The estimate is corrected for the matches caused by the collisions of the
+    /// lowest bits, so that it is unbiased, but it may be negative for nearly
+    /// disjoint sets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let first: MinHash<u64, 4096> = (0..1000_u64).collect();
+    /// let second: MinHash<u64, 4096> = (500..1500_u64).collect();
+    ///
+    /// let first_one_bit = OneBitMinHash::<4096, 64>::from(&first);
+    /// let second_one_bit = OneBitMinHash::<4096, 64>::from(&second);
+    ///
+    /// let estimate = first_one_bit.estimate_jaccard_index(&second_one_bit);
+    ///
+    /// assert!((estimate - 1.0 / 3.0).abs() < 0.1, "Estimate: {}", estimate);
+    /// assert_eq!(first_one_bit.estimate_jaccard_index(&first_one_bit), 1.0);
+    /// ```
+    pub fn estimate_jaccard_index(&self, other: &Self) -> f64 {
+        let mismatches = self
+            .bits
+            .iter()
+            .zip(other.bits.iter())
+            .map(|(left, right)| (left ^ right).count_ones() as usize)
+            .sum::<usize>();
+        let agreement = (PERMUTATIONS - mismatches) as f64 / PERMUTATIONS as f64;
+        2.0 * agreement - 1.0
+    }
+}
+
+impl<Word: Copy + Primitive<u64>, const PERMUTATIONS: usize, const WORDS: usize>
+    From<&MinHash<Word, PERMUTATIONS>> for OneBitMinHash<PERMUTATIONS, WORDS>
+{
+    /// Create a new 1-bit MinHash from the lowest bits of the words of the provided MinHash.
+    ///
+    /// Words wider than 64 bits are first converted into their highest 64 bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash: MinHash<u32, 128> = (0..1000_u64).collect();
+    /// let one_bit = OneBitMinHash::<128, 2>::from(&minhash);
+    ///
+    /// assert_eq!(one_bit, OneBitMinHash::from(minhash));
+    /// assert_eq!(one_bit.as_bits()[0] & 1, minhash[0] as u64 & 1);
+    /// ```
+    ///
+    /// A number of words other than `PERMUTATIONS / 64` does not compile:
+    ///
+    /// ```compile_fail
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash: MinHash<u32, 128> = (0..1000_u64).collect();
+    /// let one_bit = OneBitMinHash::<128, 3>::from(&minhash);
+    /// ```
+    fn from(minhash: &MinHash<Word, PERMUTATIONS>) -> Self {
+        const {
+            assert!(
+                WORDS * 64 == PERMUTATIONS,
+                "The number of words must be the number of permutations divided by 64."
+            )
+        };
+        let mut bits = [0; WORDS];
+        for (i, word) in minhash.iter().enumerate() {
+            bits[i / 64] |= (Primitive::<u64>::convert(*word) & 1) << (i % 64);
+        }
+        Self { bits }
+    }
+}
+
+impl<Word: Copy + Primitive<u64>, const PERMUTATIONS: usize, const WORDS: usize>
+    From<MinHash<Word, PERMUTATIONS>> for OneBitMinHash<PERMUTATIONS, WORDS>
+{
+    fn from(minhash: MinHash<Word, PERMUTATIONS>) -> Self {
+        Self::from(&minhash)
+    }
+}
@@ -0,0 +1,182 @@
+//! Module providing the one-permutation MinHash with optimal densification.
+//!
+//! # How does it work?
+//! The [`MinHash`] computes `PERMUTATIONS` hashes for each inserted element.
+//! The [`OnePermutationMinHash`] instead hashes each element once, uses the hash
+//! to assign the element to one of `PERMUTATIONS` bins, and keeps the minimum
+//! of each bin, making insertions about `PERMUTATIONS` times cheaper.
+//! When the set is small, some of the bins remain empty: before comparing
+//! two sketches, the empty bins are filled by the optimal densification of
+//! Shrivastava, where each empty bin probes the other bins in a sequence
+//! determined only by its own index, until it finds a non-empty one to copy.
+//! Since the probing sequence is shared by all the sketches, the densified
+//! bins of two sketches match with probability equal to the Jaccard index.
+
+use core::hash::{Hash, Hasher};
+
+use siphasher::sip128::SipHasher13;
+
+use crate::hash_iter::GOLDEN_GAMMA;
+use crate::prelude::{Maximal, Min, MinHash, Primitive, SplitMix};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OnePermutationMinHash<Word, const PERMUTATIONS: usize> {
+    bins: [Word; PERMUTATIONS],
+}
+
+impl<Word: Maximal, const PERMUTATIONS: usize> Default
+    for OnePermutationMinHash<Word, PERMUTATIONS>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Word: Maximal, const PERMUTATIONS: usize> OnePermutationMinHash<Word, PERMUTATIONS> {
+    /// Create a new OnePermutationMinHash.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash = OnePermutationMinHash::<u64, 128>::new();
+    ///
+    /// assert_eq!(minhash.number_of_empty_bins(), 128);
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            bins: [Word::maximal(); PERMUTATIONS],
+        }
+    }
+}
+
+impl<Word: Maximal + Eq, const PERMUTATIONS: usize> OnePermutationMinHash<Word, PERMUTATIONS> {
+    /// Returns the number of bins that no element was assigned to.
+    ///
+    /// # Implementative details
+    /// Empty bins are marked by the maximal value, so that a bin whose minimum
+    /// is the maximal value is considered empty. This is only likely for narrow
+    /// words, and at most causes the bin to be densified.
+    pub fn number_of_empty_bins(&self) -> usize {
+        self.bins
+            .iter()
+            .filter(|bin| **bin == Word::maximal())
+            .count()
+    }
+}
+
+impl<Word: Min + Maximal + Eq, const PERMUTATIONS: usize> OnePermutationMinHash<Word, PERMUTATIONS>
+where
+    u64: Primitive<Word>,
+{
+    /// Insert a value into the OnePermutationMinHash using the SipHasher13.
+    ///
+    /// # Arguments
+    /// * `value` - The value to insert.
+    ///
+    /// # Implementative details
+    /// The value is hashed once: the highest bits of the hash select the bin,
+    /// while the SplitMix64 finalizer of the hash provides the value to be minimized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut minhash = OnePermutationMinHash::<u64, 128>::new();
+    ///
+    /// minhash.insert(42);
+    ///
+    /// assert_eq!(minhash.number_of_empty_bins(), 127);
+    /// ```
+    pub fn insert<H: Hash>(&mut self, value: H) {
+        let mut hasher = SipHasher13::new();
+        value.hash(&mut hasher);
+        let hash = hasher.finish();
+        let bin = ((hash as u128 * PERMUTATIONS as u128) >> 64) as usize;
+        self.bins[bin].set_min(hash.splitmix().convert());
+    }
+
+    /// Returns the MinHash obtained by filling the empty bins with the optimal densification.
+    ///
+    /// # Implementative details
+    /// Each empty bin `i` probes the bins selected by the SplitMix64 sequence
+    /// seeded by `i`, and copies the first non-empty one. The resulting MinHash
+    /// can be compared with other densified MinHashes with the same number of
+    /// permutations, but values should not be inserted into it, as its words
+    /// are not the minima of the permutations used by [`MinHash`]. When all the
+    /// bins are empty, the returned MinHash is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut minhash = OnePermutationMinHash::<u64, 128>::new();
+    ///
+    /// assert!(minhash.densify().is_empty());
+    ///
+    /// for i in 0..10_u64 {
+    ///     minhash.insert(i);
+    /// }
+    ///
+    /// let densified = minhash.densify();
+    ///
+    /// assert!(minhash.number_of_empty_bins() >= 118);
+    /// assert!(densified.iter().all(|word| *word != u64::MAX));
+    /// ```
+    pub fn densify(&self) -> MinHash<Word, PERMUTATIONS> {
+        if self.number_of_empty_bins() == PERMUTATIONS {
+            return MinHash::new();
+        }
+        MinHash::from_words(core::array::from_fn(|i| {
+            let mut state = i as u64;
+            let mut bin = self.bins[i];
+            while bin == Word::maximal() {
+                state = state.wrapping_add(GOLDEN_GAMMA);
+                let probe = ((state.splitmix() as u128 * PERMUTATIONS as u128) >> 64) as usize;
+                bin = self.bins[probe];
+            }
+            bin
+        }))
+    }
+
+    /// Calculate the similarity between two OnePermutationMinHashes, after densifying them.
+    ///
+    /// # Arguments
+    /// * `other` - The other OnePermutationMinHash to compare to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut first = OnePermutationMinHash::<u64, 1024>::new();
+    /// let mut second = OnePermutationMinHash::<u64, 1024>::new();
+    ///
+    /// for i in 0..1000_u64 {
+    ///     first.insert(i);
+    ///     second.insert(i + 500);
+    /// }
+    ///
+    /// let estimate = first.estimate_jaccard_index(&second);
+    ///
+    /// assert!((estimate - 1.0 / 3.0).abs() < 0.1, "Estimate: {}", estimate);
+    /// ```
+    pub fn estimate_jaccard_index(&self, other: &Self) -> f64 {
+        self.densify().estimate_jaccard_index(&other.densify())
+    }
+}
+
+impl<Word, const PERMUTATIONS: usize> OnePermutationMinHash<Word, PERMUTATIONS> {
+    /// Iterate over the bins.
+    pub fn iter(&self) -> impl Iterator<Item = &Word> {
+        self.bins.iter()
+    }
+
+    /// Returns the number of permutations, i.e. the number of bins.
+    pub fn number_of_permutations(&self) -> usize {
+        PERMUTATIONS
+    }
+}
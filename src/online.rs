@@ -0,0 +1,99 @@
+//! Module providing an accumulator of the union of a stream of MinHashes.
+//!
+//! # Why should I use this?
+//! The union of several MinHashes is the element-wise minimum of their words,
+//! which can be maintained incrementally as new MinHashes arrive. The
+//! [`OnlineMinHash`] keeps the running union together with the number of
+//! MinHashes merged so far, and compares new MinHashes against it.
+
+use crate::prelude::{Maximal, Min, MinHash};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OnlineMinHash<Word, const PERMUTATIONS: usize> {
+    union: MinHash<Word, PERMUTATIONS>,
+    count: usize,
+}
+
+impl<Word: Maximal, const PERMUTATIONS: usize> Default for OnlineMinHash<Word, PERMUTATIONS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Word: Maximal, const PERMUTATIONS: usize> OnlineMinHash<Word, PERMUTATIONS> {
+    /// Create a new OnlineMinHash, whose running union is empty.
+    pub fn new() -> Self {
+        Self {
+            union: MinHash::new(),
+            count: 0,
+        }
+    }
+}
+
+impl<Word, const PERMUTATIONS: usize> OnlineMinHash<Word, PERMUTATIONS> {
+    /// Returns the number of MinHashes merged into the running union.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the running union.
+    pub fn union(&self) -> &MinHash<Word, PERMUTATIONS> {
+        &self.union
+    }
+
+    /// Returns the running union, consuming the accumulator.
+    pub fn into_union(self) -> MinHash<Word, PERMUTATIONS> {
+        self.union
+    }
+}
+
+impl<Word: Min + Clone + Eq, const PERMUTATIONS: usize> OnlineMinHash<Word, PERMUTATIONS> {
+    /// Merge the provided MinHash into the running union.
+    ///
+    /// # Arguments
+    /// * `minhash` - The MinHash to merge.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut online = OnlineMinHash::<u64, 128>::new();
+    ///
+    /// online.push(&(0..1000_u64).collect());
+    /// online.push(&(500..1500_u64).collect());
+    ///
+    /// assert_eq!(online.count(), 2);
+    /// assert_eq!(online.union(), &(0..1500_u64).collect());
+    /// ```
+    pub fn push(&mut self, minhash: &MinHash<Word, PERMUTATIONS>) {
+        self.union &= minhash;
+        self.count += 1;
+    }
+}
+
+impl<Word: Maximal + Eq, const PERMUTATIONS: usize> OnlineMinHash<Word, PERMUTATIONS> {
+    /// Estimate the Jaccard index between the running union and the provided MinHash.
+    ///
+    /// # Arguments
+    /// * `other` - The MinHash to compare to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut online = OnlineMinHash::<u64, 1024>::new();
+    ///
+    /// for start in [0, 1000, 2000] {
+    ///     online.push(&(start..start + 1000_u64).collect());
+    /// }
+    ///
+    /// let other: MinHash<u64, 1024> = (0..1500_u64).collect();
+    ///
+    /// assert!((online.jaccard_to(&other) - 0.5).abs() < 0.05);
+    /// ```
+    pub fn jaccard_to(&self, other: &MinHash<Word, PERMUTATIONS>) -> f64 {
+        self.union.estimate_jaccard_index(other)
+    }
+}
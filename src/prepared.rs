@@ -0,0 +1,58 @@
+//! Module providing a MinHash prepared for repeated comparisons.
+//!
+//! # Is there anything to precompute?
+//! Estimating the Jaccard index of two MinHashes counts the positions where
+//! their words are equal, which is a single pass over two contiguous arrays
+//! that the compiler already vectorizes. No property of a single MinHash,
+//! such as its populated positions, allows to skip any of the positions, as
+//! every position may match. The [`PreparedMinHash`] therefore caches nothing,
+//! and exists so that code written around prepared comparisons does not need
+//! to change should a useful precomputation arise. The bench `bench_prepared`
+//! compares the prepared and unprepared all-pairs scans.
+
+use crate::prelude::{Maximal, MinHash};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PreparedMinHash<'a, Word, const PERMUTATIONS: usize> {
+    minhash: &'a MinHash<Word, PERMUTATIONS>,
+}
+
+impl<Word, const PERMUTATIONS: usize> MinHash<Word, PERMUTATIONS> {
+    /// Returns the MinHash prepared for repeated comparisons.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let first: MinHash<u64, 128> = (0..1000_u64).collect();
+    /// let second: MinHash<u64, 128> = (500..1500_u64).collect();
+    ///
+    /// let prepared = first.prepare();
+    ///
+    /// assert_eq!(
+    ///     prepared.estimate_jaccard_index(&second),
+    ///     first.estimate_jaccard_index(&second)
+    /// );
+    /// ```
+    pub fn prepare(&self) -> PreparedMinHash<'_, Word, PERMUTATIONS> {
+        PreparedMinHash { minhash: self }
+    }
+}
+
+impl<'a, Word, const PERMUTATIONS: usize> PreparedMinHash<'a, Word, PERMUTATIONS> {
+    /// Returns the MinHash that was prepared.
+    pub fn minhash(&self) -> &'a MinHash<Word, PERMUTATIONS> {
+        self.minhash
+    }
+}
+
+impl<Word: Maximal + Eq, const PERMUTATIONS: usize> PreparedMinHash<'_, Word, PERMUTATIONS> {
+    /// Calculate the similarity between the prepared MinHash and the provided one.
+    ///
+    /// # Arguments
+    /// * `other` - The other MinHash to compare to.
+    pub fn estimate_jaccard_index(&self, other: &MinHash<Word, PERMUTATIONS>) -> f64 {
+        self.minhash.estimate_jaccard_index(other)
+    }
+}
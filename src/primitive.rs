@@ -1,3 +1,5 @@
+use crate::splitmix::SplitMix;
+
 pub trait Primitive<T> {
     fn convert(self) -> T;
 }
@@ -32,6 +34,12 @@ impl Primitive<usize> for u8 {
     }
 }
 
+impl Primitive<u128> for u8 {
+    fn convert(self) -> u128 {
+        self as u128
+    }
+}
+
 impl Primitive<u8> for u16 {
     fn convert(self) -> u8 {
         self as u8
@@ -62,6 +70,12 @@ impl Primitive<usize> for u16 {
     }
 }
 
+impl Primitive<u128> for u16 {
+    fn convert(self) -> u128 {
+        self as u128
+    }
+}
+
 impl Primitive<u8> for u32 {
     fn convert(self) -> u8 {
         self as u8
@@ -92,6 +106,12 @@ impl Primitive<usize> for u32 {
     }
 }
 
+impl Primitive<u128> for u32 {
+    fn convert(self) -> u128 {
+        self as u128
+    }
+}
+
 impl Primitive<u8> for u64 {
     fn convert(self) -> u8 {
         self as u8
@@ -122,6 +142,15 @@ impl Primitive<usize> for u64 {
     }
 }
 
+impl Primitive<u128> for u64 {
+    /// The 64 bits fill the highest half of the word, so that the conversion is
+    /// monotone as for the narrower words, while the lowest half is filled by a
+    /// further SplitMix64 mix of them, so that it is not a copy of the highest half.
+    fn convert(self) -> u128 {
+        ((self as u128) << 64) | self.splitmix() as u128
+    }
+}
+
 impl Primitive<u8> for usize {
     fn convert(self) -> u8 {
         self as u8
@@ -151,3 +180,83 @@ impl Primitive<usize> for usize {
         self
     }
 }
+
+impl Primitive<u128> for usize {
+    fn convert(self) -> u128 {
+        self as u128
+    }
+}
+
+impl Primitive<u8> for u128 {
+    fn convert(self) -> u8 {
+        self as u8
+    }
+}
+
+impl Primitive<u16> for u128 {
+    fn convert(self) -> u16 {
+        self as u16
+    }
+}
+
+impl Primitive<u32> for u128 {
+    fn convert(self) -> u32 {
+        self as u32
+    }
+}
+
+impl Primitive<u64> for u128 {
+    /// Returns the highest half of the word, which is the inverse of the conversion
+    /// of a `u64` into a `u128`, so that the conversion is monotone.
+    fn convert(self) -> u64 {
+        (self >> 64) as u64
+    }
+}
+
+impl Primitive<u128> for u128 {
+    fn convert(self) -> u128 {
+        self
+    }
+}
+
+impl Primitive<usize> for u128 {
+    fn convert(self) -> usize {
+        self as usize
+    }
+}
+
+impl Primitive<f64> for u8 {
+    fn convert(self) -> f64 {
+        self as f64
+    }
+}
+
+impl Primitive<f64> for u16 {
+    fn convert(self) -> f64 {
+        self as f64
+    }
+}
+
+impl Primitive<f64> for u32 {
+    fn convert(self) -> f64 {
+        self as f64
+    }
+}
+
+impl Primitive<f64> for u64 {
+    fn convert(self) -> f64 {
+        self as f64
+    }
+}
+
+impl Primitive<f64> for usize {
+    fn convert(self) -> f64 {
+        self as f64
+    }
+}
+
+impl Primitive<f64> for u128 {
+    fn convert(self) -> f64 {
+        self as f64
+    }
+}
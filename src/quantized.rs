@@ -0,0 +1,178 @@
+//! Module providing the quantized MinHash, for compact transmission of signatures.
+//!
+//! # What is a quantized MinHash?
+//! A quantized MinHash only keeps the highest `Q` bits of each of the words of a
+//! MinHash, packed in big-endian order into a byte buffer of `PERMUTATIONS * Q / 8` bytes.
+//! Since the words of a MinHash keep the highest bits of the hashes, the quantized
+//! words are the words of the MinHash with `Q`-bit words built from the same set,
+//! whatever the width of the original words.
+//!
+//! Differently from the [`BBitMinHash`](crate::bbit_minhash::BBitMinHash), which keeps
+//! the nearly uniform lowest bits, the highest bits of the minima shrink towards zero
+//! as the cardinality grows, so that two different minima collide with a probability
+//! that grows with the cardinality of the sets. The Jaccard index estimated from the
+//! quantized words is therefore biased upwards, and no correction is applied: `Q`
+//! should be large enough for the number of values a `Q`-bit word can assume to
+//! be much larger than the cardinality of the sets.
+
+use alloc::vec::Vec;
+
+use crate::prelude::{MinHash, Primitive};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Quantized<const PERMUTATIONS: usize, const Q: usize> {
+    bytes: Vec<u8>,
+}
+
+impl<const PERMUTATIONS: usize, const Q: usize> Quantized<PERMUTATIONS, Q> {
+    /// The number of bytes of each quantized word.
+    const BYTES_PER_WORD: usize = {
+        assert!(
+            Q == 8 || Q == 16 || Q == 32,
+            "The number of bits must be either 8, 16 or 32."
+        );
+        Q / 8
+    };
+
+    /// Returns the number of permutations.
+    pub fn number_of_permutations(&self) -> usize {
+        PERMUTATIONS
+    }
+
+    /// Returns memory required to store the quantized MinHash in bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash: MinHash<u64, 128> = (0..1000_u64).collect();
+    ///
+    /// assert_eq!(minhash.quantize::<16>().memory(), 128 * 16);
+    /// ```
+    pub fn memory(&self) -> usize {
+        self.bytes.len() * 8
+    }
+
+    /// Returns the packed bytes of the quantized words.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns the quantized MinHash with the provided packed bytes.
+    ///
+    /// # Arguments
+    /// * `bytes` - The packed bytes, `PERMUTATIONS * Q / 8` in total.
+    ///
+    /// # Returns
+    /// `None` if the number of bytes does not match the size of the quantized MinHash.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash: MinHash<u64, 128> = (0..1000_u64).collect();
+    /// let quantized = minhash.quantize::<16>();
+    ///
+    /// assert_eq!(
+    ///     Quantized::<128, 16>::from_bytes(quantized.as_bytes()),
+    ///     Some(quantized.clone())
+    /// );
+    /// assert_eq!(Quantized::<128, 16>::from_bytes(&quantized.as_bytes()[1..]), None);
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != PERMUTATIONS * Self::BYTES_PER_WORD {
+            return None;
+        }
+        Some(Self {
+            bytes: bytes.to_vec(),
+        })
+    }
+
+    /// Calculate the similarity between two quantized MinHashes.
+    ///
+    /// # Arguments
+    /// * `other` - The other quantized MinHash to compare to.
+    ///
+    /// # Implementative details
+    /// The estimate is the fraction of matching quantized words, which includes the
+    /// matches caused by the collisions of different minima on their highest `Q` bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let first: MinHash<u64, 1024> = (0..1000_u64).collect();
+    /// let second: MinHash<u64, 1024> = (500..1500_u64).collect();
+    ///
+    /// let estimate = first
+    ///     .quantize::<16>()
+    ///     .estimate_jaccard_index(&second.quantize::<16>());
+    ///
+    /// assert!((estimate - 1.0 / 3.0).abs() < 0.05, "Estimate: {}", estimate);
+    /// ```
+    pub fn estimate_jaccard_index(&self, other: &Self) -> f64 {
+        self.bytes
+            .chunks_exact(Self::BYTES_PER_WORD)
+            .zip(other.bytes.chunks_exact(Self::BYTES_PER_WORD))
+            .filter(|(left, right)| left == right)
+            .count() as f64
+            / PERMUTATIONS as f64
+    }
+}
+
+impl<Word: Copy + Primitive<u64>, const PERMUTATIONS: usize> MinHash<Word, PERMUTATIONS> {
+    /// Returns the MinHash with its words quantized to their highest `Q` bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let wide: MinHash<u64, 128> = (0..1000_u64).collect();
+    /// let narrow: MinHash<u32, 128> = (0..1000_u64).collect();
+    /// let widest: MinHash<u128, 128> = (0..1000_u64).collect();
+    ///
+    /// let quantized = wide.quantize::<16>();
+    ///
+    /// assert_eq!(quantized.as_bytes().len(), 128 * 16 / 8);
+    /// assert_eq!(quantized, narrow.quantize::<16>());
+    /// assert_eq!(quantized, widest.quantize::<16>());
+    /// ```
+    ///
+    /// Numbers of bits other than 8, 16 and 32, or larger than the words, do not compile:
+    ///
+    /// ```compile_fail
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash: MinHash<u64, 128> = (0..1000_u64).collect();
+    /// let quantized = minhash.quantize::<12>();
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash: MinHash<u16, 128> = (0..1000_u64).collect();
+    /// let quantized = minhash.quantize::<32>();
+    /// ```
+    pub fn quantize<const Q: usize>(&self) -> Quantized<PERMUTATIONS, Q> {
+        const {
+            assert!(
+                Q <= 8 * core::mem::size_of::<Word>(),
+                "The number of bits must not exceed the number of bits of the words."
+            );
+        };
+        let bytes_per_word = Quantized::<PERMUTATIONS, Q>::BYTES_PER_WORD;
+        let word_bits = 8 * core::mem::size_of::<Word>().min(8);
+        let mut bytes = Vec::with_capacity(PERMUTATIONS * bytes_per_word);
+        for word in self.iter() {
+            // We align the highest bits of the word with the highest bits of the u64,
+            // where words wider than 64 bits are converted into their highest 64 bits.
+            let value = Primitive::<u64>::convert(*word) << (64 - word_bits);
+            bytes.extend_from_slice(&value.to_be_bytes()[..bytes_per_word]);
+        }
+        Quantized { bytes }
+    }
+}
@@ -0,0 +1,49 @@
+//! Module providing the generation of random MinHashes with the rand crate.
+//!
+//! # Why should I use this?
+//! Random MinHashes, whose words are independent and uniformly distributed, are
+//! useful to fuzz the code paths merging and comparing MinHashes without
+//! inserting many random values. Note that they are not the MinHash of any set
+//! of values in particular, and are only meant for testing.
+//!
+//! # Examples
+//!
+//! ```
+//! use minhash_rs::prelude::*;
+//! use rand::{rngs::SmallRng, Rng, SeedableRng};
+//!
+//! let mut rng = SmallRng::seed_from_u64(42);
+//!
+//! let first: MinHash<u64, 128> = rng.gen();
+//! let second = MinHash::<u64, 128>::random(&mut rng);
+//!
+//! assert_eq!(first.estimate_jaccard_index(&first), 1.0);
+//! assert_ne!(first, second);
+//! ```
+
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+
+use crate::prelude::MinHash;
+
+impl<Word, const PERMUTATIONS: usize> Distribution<MinHash<Word, PERMUTATIONS>> for Standard
+where
+    Standard: Distribution<Word>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> MinHash<Word, PERMUTATIONS> {
+        MinHash::from_words(core::array::from_fn(|_| self.sample(rng)))
+    }
+}
+
+impl<Word, const PERMUTATIONS: usize> MinHash<Word, PERMUTATIONS>
+where
+    Standard: Distribution<Word>,
+{
+    /// Create a new MinHash whose words are uniformly random.
+    ///
+    /// # Arguments
+    /// * `rng` - The random number generator to use.
+    pub fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        <Standard as Distribution<Self>>::sample(&Standard, rng)
+    }
+}
@@ -0,0 +1,177 @@
+//! Module providing the serde serialization and deserialization of MinHash and MinHashArray.
+//!
+//! # Implementative details
+//! Serde only provides implementations for arrays of up to 32 elements, so
+//! the words of the MinHash are serialized as a tuple of `PERMUTATIONS` elements,
+//! and the deserialization checks that exactly `PERMUTATIONS` elements are provided.
+//! The same applies to the MinHashArray, which is serialized as a tuple of `N` MinHash.
+//!
+//! # Examples
+//! We can round-trip a MinHash through JSON:
+//!
+//! ```
+//! use minhash_rs::prelude::*;
+//!
+//! let minhash: MinHash<u64, 128> = (0..1000_u64).collect();
+//!
+//! let serialized = serde_json::to_string(&minhash).unwrap();
+//! let deserialized: MinHash<u64, 128> = serde_json::from_str(&serialized).unwrap();
+//!
+//! assert_eq!(minhash, deserialized);
+//! assert_eq!(serialized, serde_json::to_string(&deserialized).unwrap());
+//! ```
+//!
+//! And through bincode:
+//!
+//! ```
+//! use minhash_rs::prelude::*;
+//!
+//! let minhash: MinHash<u8, 64> = (0..1000_u64).collect();
+//!
+//! let serialized = bincode::serialize(&minhash).unwrap();
+//! let deserialized: MinHash<u8, 64> = bincode::deserialize(&serialized).unwrap();
+//!
+//! assert_eq!(serialized.len(), 64);
+//! assert_eq!(minhash, deserialized);
+//! assert_eq!(serialized, bincode::serialize(&deserialized).unwrap());
+//! ```
+//!
+//! Deserializing a sequence of the wrong length fails:
+//!
+//! ```
+//! use minhash_rs::prelude::*;
+//!
+//! assert!(serde_json::from_str::<MinHash<u8, 4>>("[1, 2, 3]").is_err());
+//! assert!(serde_json::from_str::<MinHash<u8, 4>>("[1, 2, 3, 4, 5]").is_err());
+//! assert!(serde_json::from_str::<MinHash<u8, 4>>("[1, 2, 3, 4]").is_ok());
+//! ```
+//!
+//! The MinHashArray is supported as well:
+//!
+//! ```
+//! use minhash_rs::prelude::*;
+//!
+//! let mut array = MinHashArray::<u32, 64, 3>::new();
+//! array[0].insert_with_siphashes13(42);
+//! array[2].insert_with_siphashes13(47);
+//!
+//! let serialized = serde_json::to_string(&array).unwrap();
+//! let deserialized: MinHashArray<u32, 64, 3> = serde_json::from_str(&serialized).unwrap();
+//! assert_eq!(array, deserialized);
+//!
+//! let serialized = bincode::serialize(&array).unwrap();
+//! let deserialized: MinHashArray<u32, 64, 3> = bincode::deserialize(&serialized).unwrap();
+//! assert_eq!(array, deserialized);
+//! assert_eq!(serialized, bincode::serialize(&deserialized).unwrap());
+//!
+//! assert!(serde_json::from_str::<MinHashArray<u32, 64, 2>>(
+//!     &serde_json::to_string(&array).unwrap()
+//! ).is_err());
+//! ```
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use serde::de::{Error, IgnoredAny, SeqAccess, Visitor};
+use serde::ser::SerializeTuple;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::prelude::{Maximal, MinHash, MinHashArray};
+
+impl<Word: Serialize, const PERMUTATIONS: usize> Serialize for MinHash<Word, PERMUTATIONS> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(PERMUTATIONS)?;
+        for word in self.iter() {
+            tuple.serialize_element(word)?;
+        }
+        tuple.end()
+    }
+}
+
+/// Visitor filling a MinHash with exactly `PERMUTATIONS` words.
+struct MinHashVisitor<Word, const PERMUTATIONS: usize> {
+    _word: PhantomData<Word>,
+}
+
+impl<'de, Word: Deserialize<'de> + Maximal, const PERMUTATIONS: usize> Visitor<'de>
+    for MinHashVisitor<Word, PERMUTATIONS>
+{
+    type Value = MinHash<Word, PERMUTATIONS>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a sequence of {} words", PERMUTATIONS)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut minhash = MinHash::new();
+        for (i, word) in minhash.iter_mut().enumerate() {
+            *word = seq
+                .next_element()?
+                .ok_or_else(|| Error::invalid_length(i, &self))?;
+        }
+        if seq.next_element::<IgnoredAny>()?.is_some() {
+            return Err(Error::invalid_length(PERMUTATIONS + 1, &self));
+        }
+        Ok(minhash)
+    }
+}
+
+impl<'de, Word: Deserialize<'de> + Maximal, const PERMUTATIONS: usize> Deserialize<'de>
+    for MinHash<Word, PERMUTATIONS>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_tuple(PERMUTATIONS, MinHashVisitor { _word: PhantomData })
+    }
+}
+
+impl<Word: Serialize + Maximal, const PERMUTATIONS: usize, const N: usize> Serialize
+    for MinHashArray<Word, PERMUTATIONS, N>
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(N)?;
+        for i in 0..N {
+            tuple.serialize_element(&self[i])?;
+        }
+        tuple.end()
+    }
+}
+
+/// Visitor filling a MinHashArray with exactly `N` MinHash.
+struct MinHashArrayVisitor<Word, const PERMUTATIONS: usize, const N: usize> {
+    _word: PhantomData<Word>,
+}
+
+impl<'de, Word: Deserialize<'de> + Maximal, const PERMUTATIONS: usize, const N: usize> Visitor<'de>
+    for MinHashArrayVisitor<Word, PERMUTATIONS, N>
+{
+    type Value = MinHashArray<Word, PERMUTATIONS, N>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "a sequence of {} MinHash of {} words",
+            N, PERMUTATIONS
+        )
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut array = MinHashArray::new();
+        for i in 0..N {
+            array[i] = seq
+                .next_element()?
+                .ok_or_else(|| Error::invalid_length(i, &self))?;
+        }
+        if seq.next_element::<IgnoredAny>()?.is_some() {
+            return Err(Error::invalid_length(N + 1, &self));
+        }
+        Ok(array)
+    }
+}
+
+impl<'de, Word: Deserialize<'de> + Maximal, const PERMUTATIONS: usize, const N: usize>
+    Deserialize<'de> for MinHashArray<Word, PERMUTATIONS, N>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_tuple(N, MinHashArrayVisitor { _word: PhantomData })
+    }
+}
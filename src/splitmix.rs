@@ -2,11 +2,69 @@
 //!
 //! # What is SplitMix64?
 //! SplitMix64 is a fast, non-cryptographic, pseudo-random number generator.
+//! It is used in this crate to generate the permutations for the MinHash.
+//!
+//! # Narrower words
+//! The narrower words have their own finalizers of the same shape, alternating
+//! xor-shifts and multiplications by odd constants, so that each of them is a
+//! bijection on its width. The `u32` finalizer uses the constants of the
+//! MurmurHash3 `fmix32`, the `u16` one the constants found by Chris Wellons'
+//! hash prospector, and the `u8` one the constants found by an exhaustive
+//! search of the lowest avalanche bias over all the 256 inputs.
+
+use crate::hash_iter::GOLDEN_GAMMA;
 
 pub trait SplitMix {
     fn splitmix(self) -> Self;
 }
 
+/// The stateful SplitMix64 generator.
+///
+/// # Implementative details
+/// Each call to [`SplitMix64::next_u64`] increments the state by the golden gamma
+/// `0x9e3779b97f4a7c15` and returns the state mixed by the [`SplitMix`] finalizer,
+/// as in the reference implementation by Sebastiano Vigna. The hashes of each
+/// permutation of a MinHash are the outputs of the generator seeded by the hash
+/// of the inserted value.
+///
+/// # Examples
+///
+/// ```
+/// use minhash_rs::prelude::*;
+///
+/// let mut generator = SplitMix64::new(0);
+///
+/// assert_eq!(generator.next_u64(), 0xe220a8397b1dcdaf);
+/// assert_eq!(generator.next_u64(), 0x6e789e6aa1b965f4);
+/// assert_eq!(generator.next_u64(), 0x06c45d188009454f);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    /// Create a new SplitMix64 generator with the provided seed.
+    ///
+    /// # Arguments
+    /// * `seed` - The initial state of the generator.
+    pub const fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Returns the current state of the generator.
+    pub const fn state(&self) -> u64 {
+        self.state
+    }
+
+    /// Returns the next value of the sequence.
+    #[inline(always)]
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(GOLDEN_GAMMA);
+        self.state.splitmix()
+    }
+}
+
 impl SplitMix for u64 {
     fn splitmix(self) -> Self {
         let mut z = self;
@@ -15,3 +73,30 @@ impl SplitMix for u64 {
         z ^ (z >> 31)
     }
 }
+
+impl SplitMix for u32 {
+    fn splitmix(self) -> Self {
+        let mut z = self;
+        z = (z ^ (z >> 16)).wrapping_mul(0x85ebca6b);
+        z = (z ^ (z >> 13)).wrapping_mul(0xc2b2ae35);
+        z ^ (z >> 16)
+    }
+}
+
+impl SplitMix for u16 {
+    fn splitmix(self) -> Self {
+        let mut z = self;
+        z = (z ^ (z >> 8)).wrapping_mul(0x88b5);
+        z = (z ^ (z >> 7)).wrapping_mul(0xdb2d);
+        z ^ (z >> 9)
+    }
+}
+
+impl SplitMix for u8 {
+    fn splitmix(self) -> Self {
+        let mut z = self;
+        z = (z ^ (z >> 2)).wrapping_mul(0x69);
+        z = (z ^ (z >> 3)).wrapping_mul(0x5b);
+        z ^ (z >> 4)
+    }
+}
@@ -0,0 +1,186 @@
+//! Module providing the conversion of MinHash words to and from raw bytes.
+//!
+//! # Implementative details
+//! The bytes of a MinHash are the bytes of its words in order, each written in
+//! the chosen endianness, with no header: the number of permutations and the
+//! word width are part of the type, and the length of the bytes is validated
+//! when reading them back. Differently from the serde support, this requires
+//! no dependency.
+//!
+//! # Examples
+//!
+//! ```
+//! use minhash_rs::prelude::*;
+//!
+//! let minhash: MinHash<u32, 128> = (0..1000_u64).collect();
+//!
+//! let bytes = minhash.to_le_bytes();
+//!
+//! assert_eq!(bytes.len(), MinHash::<u32, 128>::MEMORY_BYTES);
+//! assert_eq!(MinHash::<u32, 128>::from_le_bytes(&bytes), Some(minhash));
+//! assert_eq!(MinHash::<u32, 128>::from_le_bytes(&bytes[1..]), None);
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::prelude::MinHash;
+
+/// Trait for the words that can be written to and read from a fixed number of raw bytes.
+///
+/// It is implemented for all the unsigned integer word types, and is used by the
+/// byte conversions of [`MinHash`] to write each of its words in turn.
+pub trait ToBytes: Sized {
+    /// The number of bytes of the word.
+    const BYTES: usize;
+
+    /// Writes the little-endian bytes of the word into the provided slice.
+    ///
+    /// # Arguments
+    /// * `bytes` - The slice of exactly `BYTES` bytes to write into.
+    fn write_le_bytes(self, bytes: &mut [u8]);
+
+    /// Writes the big-endian bytes of the word into the provided slice.
+    ///
+    /// # Arguments
+    /// * `bytes` - The slice of exactly `BYTES` bytes to write into.
+    fn write_be_bytes(self, bytes: &mut [u8]);
+
+    /// Reads the word from the provided little-endian bytes.
+    ///
+    /// # Arguments
+    /// * `bytes` - The slice of exactly `BYTES` bytes to read from.
+    fn read_le_bytes(bytes: &[u8]) -> Self;
+
+    /// Reads the word from the provided big-endian bytes.
+    ///
+    /// # Arguments
+    /// * `bytes` - The slice of exactly `BYTES` bytes to read from.
+    fn read_be_bytes(bytes: &[u8]) -> Self;
+}
+
+macro_rules! impl_to_bytes {
+    ($($word:ty),*) => {
+        $(
+            impl ToBytes for $word {
+                const BYTES: usize = core::mem::size_of::<$word>();
+
+                fn write_le_bytes(self, bytes: &mut [u8]) {
+                    bytes.copy_from_slice(&self.to_le_bytes());
+                }
+
+                fn write_be_bytes(self, bytes: &mut [u8]) {
+                    bytes.copy_from_slice(&self.to_be_bytes());
+                }
+
+                fn read_le_bytes(bytes: &[u8]) -> Self {
+                    <$word>::from_le_bytes(bytes.try_into().unwrap())
+                }
+
+                fn read_be_bytes(bytes: &[u8]) -> Self {
+                    <$word>::from_be_bytes(bytes.try_into().unwrap())
+                }
+            }
+        )*
+    };
+}
+
+impl_to_bytes!(u8, u16, u32, u64, u128, usize);
+
+impl<Word: ToBytes + Copy, const PERMUTATIONS: usize> MinHash<Word, PERMUTATIONS> {
+    /// Returns the little-endian bytes of the words of the MinHash.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash = MinHash::from_words([0x0102_u16, 0x0304]);
+    ///
+    /// assert_eq!(minhash.to_le_bytes(), vec![0x02, 0x01, 0x04, 0x03]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_le_bytes(&self) -> Vec<u8> {
+        let mut bytes = alloc::vec![0; PERMUTATIONS * Word::BYTES];
+        for (word, chunk) in self.iter().zip(bytes.chunks_exact_mut(Word::BYTES)) {
+            word.write_le_bytes(chunk);
+        }
+        bytes
+    }
+
+    /// Returns the big-endian bytes of the words of the MinHash.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash = MinHash::from_words([0x0102_u16, 0x0304]);
+    ///
+    /// assert_eq!(minhash.to_be_bytes(), vec![0x01, 0x02, 0x03, 0x04]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_be_bytes(&self) -> Vec<u8> {
+        let mut bytes = alloc::vec![0; PERMUTATIONS * Word::BYTES];
+        for (word, chunk) in self.iter().zip(bytes.chunks_exact_mut(Word::BYTES)) {
+            word.write_be_bytes(chunk);
+        }
+        bytes
+    }
+
+    /// Returns the MinHash with the words read from the provided little-endian bytes.
+    ///
+    /// # Arguments
+    /// * `bytes` - The bytes of the words, `PERMUTATIONS * size_of::<Word>()` in total.
+    ///
+    /// # Returns
+    /// `None` if the number of bytes does not match the size of the MinHash.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// assert_eq!(
+    ///     MinHash::<u16, 2>::from_le_bytes(&[0x02, 0x01, 0x04, 0x03]),
+    ///     Some(MinHash::from_words([0x0102, 0x0304]))
+    /// );
+    /// assert_eq!(MinHash::<u16, 2>::from_le_bytes(&[0x02, 0x01, 0x04]), None);
+    /// ```
+    pub fn from_le_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != PERMUTATIONS * Word::BYTES {
+            return None;
+        }
+        Some(Self::from_words(core::array::from_fn(|i| {
+            Word::read_le_bytes(&bytes[i * Word::BYTES..(i + 1) * Word::BYTES])
+        })))
+    }
+
+    /// Returns the MinHash with the words read from the provided big-endian bytes.
+    ///
+    /// # Arguments
+    /// * `bytes` - The bytes of the words, `PERMUTATIONS * size_of::<Word>()` in total.
+    ///
+    /// # Returns
+    /// `None` if the number of bytes does not match the size of the MinHash.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// assert_eq!(
+    ///     MinHash::<u16, 2>::from_be_bytes(&[0x01, 0x02, 0x03, 0x04]),
+    ///     Some(MinHash::from_words([0x0102, 0x0304]))
+    /// );
+    /// assert_eq!(MinHash::<u16, 2>::from_be_bytes(&[]), None);
+    /// ```
+    pub fn from_be_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != PERMUTATIONS * Word::BYTES {
+            return None;
+        }
+        Some(Self::from_words(core::array::from_fn(|i| {
+            Word::read_be_bytes(&bytes[i * Word::BYTES..(i + 1) * Word::BYTES])
+        })))
+    }
+}
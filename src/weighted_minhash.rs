@@ -0,0 +1,175 @@
+//! Module providing a MinHash for weighted sets, such as multisets.
+//!
+//! # How does it work?
+//! The [`WeightedMinHash`] implements the Improved Consistent Weighted Sampling
+//! by Ioffe. For each permutation, every feature with weight `w` draws, from
+//! random values seeded by the hash of the feature, a quantized weight `t` and a
+//! score `a`: the permutation keeps the pair `(feature, t)` with the smallest score.
+//! Two weighted sets keep the same pair with probability equal to their generalized
+//! Jaccard index, `sum(min(w_A, w_B)) / sum(max(w_A, w_B))`. The scores are kept in
+//! `f64`, while the selected pairs are hashed into words, which are the ones compared.
+
+use core::hash::{Hash, Hasher};
+
+use siphasher::sip128::SipHasher13;
+
+use crate::hash_iter::GOLDEN_GAMMA;
+use crate::prelude::{Maximal, Primitive, SplitMix};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WeightedMinHash<Word, const PERMUTATIONS: usize> {
+    scores: [f64; PERMUTATIONS],
+    words: [Word; PERMUTATIONS],
+}
+
+impl<Word: Maximal, const PERMUTATIONS: usize> Default for WeightedMinHash<Word, PERMUTATIONS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Word: Maximal, const PERMUTATIONS: usize> WeightedMinHash<Word, PERMUTATIONS> {
+    /// Create a new WeightedMinHash.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let minhash = WeightedMinHash::<u64, 128>::new();
+    ///
+    /// assert!(minhash.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            scores: [f64::INFINITY; PERMUTATIONS],
+            words: [Word::maximal(); PERMUTATIONS],
+        }
+    }
+
+    /// Returns whether no feature with positive weight was inserted in the WeightedMinHash.
+    pub fn is_empty(&self) -> bool {
+        self.scores.iter().all(|score| *score == f64::INFINITY)
+    }
+}
+
+impl<Word, const PERMUTATIONS: usize> WeightedMinHash<Word, PERMUTATIONS> {
+    /// Iterate over the words.
+    pub fn iter(&self) -> impl Iterator<Item = &Word> {
+        self.words.iter()
+    }
+
+    /// Returns the number of permutations.
+    pub fn number_of_permutations(&self) -> usize {
+        PERMUTATIONS
+    }
+}
+
+impl<Word: Copy, const PERMUTATIONS: usize> WeightedMinHash<Word, PERMUTATIONS>
+where
+    u64: Primitive<Word>,
+{
+    /// Insert a weighted feature into the WeightedMinHash using the SipHasher13.
+    ///
+    /// # Arguments
+    /// * `value` - The feature to insert.
+    /// * `weight` - The non-negative weight of the feature.
+    ///
+    /// # Implementative details
+    /// Each feature should be inserted once, with its total weight, as inserting
+    /// the same feature twice does not sum its weights. Features with zero weight
+    /// are not inserted.
+    ///
+    /// # Panics
+    /// If the weight is negative or not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut minhash = WeightedMinHash::<u64, 128>::new();
+    ///
+    /// minhash.insert_weighted("zero", 0.0);
+    /// assert!(minhash.is_empty());
+    /// minhash.insert_weighted("apple", 3.0);
+    /// assert!(!minhash.is_empty());
+    /// ```
+    pub fn insert_weighted<H: Hash>(&mut self, value: H, weight: f64) {
+        assert!(
+            weight.is_finite() && weight >= 0.0,
+            "The weight ({}) must be finite and non-negative.",
+            weight
+        );
+        if weight == 0.0 {
+            return;
+        }
+
+        let mut hasher = SipHasher13::new();
+        value.hash(&mut hasher);
+        let feature = hasher.finish();
+        let log_weight = libm::log(weight);
+
+        let mut state = feature;
+        let mut uniform = || {
+            state = state.wrapping_add(GOLDEN_GAMMA);
+            // The 53 highest bits are mapped to an uniform value in (0, 1].
+            ((state.splitmix() >> 11) + 1) as f64 / (1_u64 << 53) as f64
+        };
+
+        for (score, word) in self.scores.iter_mut().zip(self.words.iter_mut()) {
+            // The sum of two exponential values follows a Gamma(2, 1) distribution.
+            let rate = -libm::log(uniform() * uniform());
+            let scale = -libm::log(uniform() * uniform());
+            let offset = uniform();
+
+            let quantized = libm::floor(log_weight / rate + offset);
+            let candidate = scale / libm::exp(rate * (quantized - offset + 1.0));
+
+            if candidate < *score {
+                *score = candidate;
+                *word = feature
+                    .wrapping_add((quantized as i64 as u64).wrapping_mul(GOLDEN_GAMMA))
+                    .splitmix()
+                    .convert();
+            }
+        }
+    }
+}
+
+impl<Word: Eq, const PERMUTATIONS: usize> WeightedMinHash<Word, PERMUTATIONS> {
+    /// Calculate the generalized Jaccard index between two WeightedMinHashes.
+    ///
+    /// # Arguments
+    /// * `other` - The other WeightedMinHash to compare to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minhash_rs::prelude::*;
+    ///
+    /// let mut first = WeightedMinHash::<u64, 1024>::new();
+    /// let mut second = WeightedMinHash::<u64, 1024>::new();
+    ///
+    /// for (feature, weight) in [("apple", 3.0), ("banana", 1.0), ("cherry", 2.0)] {
+    ///     first.insert_weighted(feature, weight);
+    /// }
+    /// for (feature, weight) in [("apple", 1.0), ("banana", 1.0), ("date", 2.0)] {
+    ///     second.insert_weighted(feature, weight);
+    /// }
+    ///
+    /// // The exact generalized Jaccard index is (1 + 1) / (3 + 1 + 2 + 2).
+    /// let estimate = first.estimate_weighted_jaccard(&second);
+    ///
+    /// assert!((estimate - 0.25).abs() < 0.1, "Estimate: {}", estimate);
+    /// assert_eq!(first.estimate_weighted_jaccard(&first), 1.0);
+    /// ```
+    pub fn estimate_weighted_jaccard(&self, other: &Self) -> f64 {
+        self.words
+            .iter()
+            .zip(other.words.iter())
+            .filter(|(l, r)| l == r)
+            .count() as f64
+            / PERMUTATIONS as f64
+    }
+}
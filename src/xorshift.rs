@@ -2,7 +2,7 @@
 //!
 //! # What is XorShift?
 //! XorShift is a fast, non-cryptographic, pseudo-random number generator.
-//! It is used in this crate to generate the permutations for the MinHash.
+//! It is made available to generate pseudo-random values, as done in the test suite.
 
 pub trait XorShift {
     /// Returns the next value in the xorshift sequence.
@@ -10,8 +10,39 @@ pub trait XorShift {
 }
 
 impl XorShift for usize {
+    /// Follows the sequence of the unsigned integer of the same width as the pointer.
+    #[cfg(target_pointer_width = "64")]
     fn xorshift(&mut self) -> Self {
-        (*self as u64).xorshift() as usize
+        let mut state = *self as u64;
+        *self = state.xorshift() as usize;
+        *self
+    }
+
+    /// Follows the sequence of the unsigned integer of the same width as the pointer.
+    #[cfg(target_pointer_width = "32")]
+    fn xorshift(&mut self) -> Self {
+        let mut state = *self as u32;
+        *self = state.xorshift() as usize;
+        *self
+    }
+
+    /// Follows the sequence of the unsigned integer of the same width as the pointer.
+    #[cfg(target_pointer_width = "16")]
+    fn xorshift(&mut self) -> Self {
+        let mut state = *self as u16;
+        *self = state.xorshift() as usize;
+        *self
+    }
+}
+
+impl XorShift for u128 {
+    /// The triple (22, 35, 41) yields the full period of 2^128 - 1,
+    /// as its characteristic polynomial is primitive.
+    fn xorshift(&mut self) -> Self {
+        *self ^= *self << 22;
+        *self ^= *self >> 35;
+        *self ^= *self << 41;
+        *self
     }
 }
 
@@ -34,12 +65,19 @@ impl XorShift for u32 {
 }
 
 impl XorShift for u16 {
+    /// The triple (7, 9, 8) yields the full period of 2^16 - 1,
+    /// so the generator visits all the non-zero values before repeating.
     fn xorshift(&mut self) -> Self {
-        (*self as u32).xorshift() as u16
+        *self ^= *self << 7;
+        *self ^= *self >> 9;
+        *self ^= *self << 8;
+        *self
     }
 }
 
 impl XorShift for u8 {
+    /// The triple (3, 7, 1) yields the full period of 2^8 - 1,
+    /// so the generator visits all the non-zero values before repeating.
     fn xorshift(&mut self) -> Self {
         *self ^= *self << 3;
         *self ^= *self >> 7;
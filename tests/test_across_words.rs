@@ -0,0 +1,60 @@
+//! This test module checks that MinHashes with different word types built from
+//! the same sets can be compared with each other.
+use minhash_rs::prelude::*;
+
+#[test]
+pub fn test_jaccard_across_words() {
+    for seed in 0..20_u64 {
+        let offset = seed * 1_000_000;
+        let first_u32: MinHash<u32, 128> = (offset..offset + 1000).collect();
+        let first_u64: MinHash<u64, 128> = (offset..offset + 1000).collect();
+        let first_u16: MinHash<u16, 128> = (offset..offset + 1000).collect();
+        let first_u128: MinHash<u128, 128> = (offset..offset + 1000).collect();
+        let second_u64: MinHash<u64, 128> = (offset + 100..offset + 1100).collect();
+
+        assert_eq!(
+            first_u32.estimate_jaccard_index_across_words(&first_u64),
+            1.0
+        );
+        assert_eq!(
+            first_u16.estimate_jaccard_index_across_words(&first_u64),
+            1.0
+        );
+        assert_eq!(
+            first_u16.estimate_jaccard_index_across_words(&first_u32),
+            1.0
+        );
+        for estimate in [
+            first_u128.estimate_jaccard_index_across_words(&first_u64),
+            first_u128.estimate_jaccard_index_across_words(&first_u32),
+            first_u128.estimate_jaccard_index_across_words(&first_u16),
+            first_u64.estimate_jaccard_index_across_words(&first_u128),
+            first_u32.estimate_jaccard_index_across_words(&first_u128),
+            first_u16.estimate_jaccard_index_across_words(&first_u128),
+        ] {
+            assert_eq!(estimate, 1.0);
+        }
+
+        let narrowed = first_u32.estimate_jaccard_index_across_words(&second_u64);
+        let wide = first_u64.estimate_jaccard_index(&second_u64);
+        assert!(narrowed >= wide);
+        assert!(
+            narrowed - wide < 0.05,
+            "Narrowed: {}, wide: {}",
+            narrowed,
+            wide
+        );
+    }
+}
+
+#[test]
+pub fn test_u128_words_extend_u64_words() {
+    let wide: MinHash<u128, 128> = (0..1000_u64).collect();
+    let narrow: MinHash<u64, 128> = (0..1000_u64).collect();
+
+    for (wide, narrow) in wide.iter().zip(narrow.iter()) {
+        // The highest half is the hash, while the lowest half is not a copy of it.
+        assert_eq!((*wide >> 64) as u64, *narrow);
+        assert_ne!(*wide as u64, *narrow);
+    }
+}
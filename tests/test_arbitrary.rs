@@ -0,0 +1,55 @@
+//! This test module exercises the merge and the Jaccard estimation on arbitrary MinHashes.
+#![cfg(feature = "arbitrary")]
+use arbitrary::{Arbitrary, Unstructured};
+use minhash_rs::prelude::*;
+
+fn exercise<'a, Word>(unstructured: &mut Unstructured<'a>)
+where
+    Word: Arbitrary<'a> + Min + Maximal + Copy + Eq + Ord + core::fmt::Debug,
+{
+    let first = MinHash::<Word, 64>::arbitrary(unstructured).unwrap();
+    let second = MinHash::<Word, 64>::arbitrary(unstructured).unwrap();
+
+    let mut union = first;
+    union |= &second;
+    let mut merged = MinHash::new();
+    first.merge_into(&second, &mut merged);
+    assert_eq!(union, merged);
+    assert!(union
+        .iter()
+        .zip(first.iter().zip(second.iter()))
+        .all(|(u, (f, s))| u <= f && u <= s));
+
+    for (left, right) in [(&first, &second), (&first, &union), (&union, &second)] {
+        let jaccard = left.estimate_jaccard_index(right);
+        assert!(jaccard.is_nan() || (0.0..=1.0).contains(&jaccard));
+        assert_eq!(jaccard.is_nan(), left.is_empty() && right.is_empty());
+    }
+}
+
+#[test]
+pub fn test_arbitrary_merge_and_jaccard() {
+    // A fixed pseudo-random byte buffer, as a fuzzer would provide.
+    let mut state = 0x0123456789ABCDEF_u64;
+    let bytes: Vec<u8> = (0..1 << 16)
+        .map(|_| {
+            state = state.splitmix();
+            state as u8
+        })
+        .collect();
+
+    let mut unstructured = Unstructured::new(&bytes);
+    for _ in 0..20 {
+        exercise::<u8>(&mut unstructured);
+        exercise::<u16>(&mut unstructured);
+        exercise::<u32>(&mut unstructured);
+        exercise::<u64>(&mut unstructured);
+    }
+
+    // Empty and saturated MinHashes are generated as well.
+    let empty = MinHash::<u8, 64>::arbitrary(&mut Unstructured::new(&[u8::MAX; 64])).unwrap();
+    let full = MinHash::<u8, 64>::arbitrary(&mut Unstructured::new(&[0; 64])).unwrap();
+    assert!(empty.is_empty());
+    assert!(full.is_full());
+    assert_eq!(empty.estimate_jaccard_index(&full), 0.0);
+}
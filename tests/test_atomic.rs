@@ -0,0 +1,135 @@
+//! This test module checks that the MinHashes built by several threads inserting
+//! values atomically are identical to the ones built by a single thread.
+use core::sync::atomic::{AtomicU16, AtomicU32, AtomicU64, Ordering};
+use minhash_rs::prelude::*;
+
+const THREADS: u64 = 8;
+const VALUES_PER_THREAD: u64 = 10_000;
+
+#[test]
+pub fn test_atomic_fvn_insertions() {
+    let key = 0x0123456789ABCDEF;
+    let atomic = ConcurrentMinHash::<AtomicU64, 128>::new();
+    let keyed_atomic = ConcurrentMinHash::<AtomicU64, 128>::new();
+
+    std::thread::scope(|scope| {
+        for thread in 0..THREADS {
+            let atomic = &atomic;
+            let keyed_atomic = &keyed_atomic;
+            scope.spawn(move || {
+                let start = thread * VALUES_PER_THREAD;
+                for value in start..start + VALUES_PER_THREAD {
+                    atomic.fetch_insert_with_fvn(value, Ordering::Relaxed);
+                    keyed_atomic.fetch_insert_with_keyed_fvn(value, key, Ordering::Relaxed);
+                }
+            });
+        }
+    });
+
+    let mut sequential = MinHash::<u64, 128>::new();
+    let mut keyed_sequential = MinHash::<u64, 128>::new();
+    for value in 0..THREADS * VALUES_PER_THREAD {
+        sequential.insert_with_fvn(value);
+        keyed_sequential.insert_with_keyed_fvn(value, key);
+    }
+
+    assert_eq!(atomic.into_minhash(), sequential);
+    assert_eq!(keyed_atomic.into_minhash(), keyed_sequential);
+}
+
+/// Repeatedly inserts overlapping ranges from several threads, and checks that
+/// the result is identical to the sequential one.
+fn stress_atomic<Word, AtomicWord>()
+where
+    Word: Min + XorShift + Copy + Eq + Maximal + Zero + Send + Sync + core::fmt::Debug,
+    AtomicWord: AtomicFetchMin<Word = Word> + Sync,
+    u64: Primitive<Word>,
+{
+    for round in 0..20_u64 {
+        let atomic = ConcurrentMinHash::<AtomicWord, 64>::new();
+        std::thread::scope(|scope| {
+            for thread in 0..THREADS {
+                let atomic = &atomic;
+                scope.spawn(move || {
+                    // Each thread inserts half of the values of the next thread as well.
+                    let start = round * 1_000_000 + thread * 500;
+                    for value in start..start + 1_000 {
+                        atomic.fetch_insert_with_siphashes13(value, Ordering::Relaxed);
+                    }
+                });
+            }
+        });
+
+        let mut sequential = MinHash::<Word, 64>::new();
+        for value in round * 1_000_000..round * 1_000_000 + (THREADS + 1) * 500 {
+            sequential.insert_with_siphashes13(value);
+        }
+
+        assert_eq!(atomic.into_minhash(), sequential);
+    }
+}
+
+#[test]
+pub fn test_atomic_stress() {
+    use core::sync::atomic::{AtomicU8, AtomicUsize};
+
+    stress_atomic::<u8, AtomicU8>();
+    stress_atomic::<u16, AtomicU16>();
+    stress_atomic::<u32, AtomicU32>();
+    stress_atomic::<u64, AtomicU64>();
+    stress_atomic::<usize, AtomicUsize>();
+}
+
+#[test]
+pub fn test_atomic_relaxed_many_threads() {
+    const MANY_THREADS: u64 = 32;
+
+    let relaxed = ConcurrentMinHash::<AtomicU32, 128>::new();
+    let seqcst = ConcurrentMinHash::<AtomicU32, 128>::new();
+    std::thread::scope(|scope| {
+        for thread in 0..MANY_THREADS {
+            let relaxed = &relaxed;
+            let seqcst = &seqcst;
+            scope.spawn(move || {
+                // Each thread inserts half of the values of the next thread as well.
+                for value in thread * 5_000..thread * 5_000 + VALUES_PER_THREAD {
+                    relaxed.fetch_insert_with_siphashes13_relaxed(value);
+                    seqcst.fetch_insert_with_siphashes13_seqcst(value);
+                }
+            });
+        }
+    });
+
+    let sequential: MinHash<u32, 128> = (0..(MANY_THREADS + 1) * 5_000).collect();
+
+    assert_eq!(relaxed.into_minhash(), sequential);
+    assert_eq!(seqcst.into_minhash(), sequential);
+}
+
+#[test]
+pub fn test_atomic_minhash_array() {
+    let array = ConcurrentMinHashArray::<AtomicU16, 128, 4>::new();
+    std::thread::scope(|scope| {
+        for thread in 0..THREADS {
+            let array = &array;
+            scope.spawn(move || {
+                // Each thread inserts half of the values of the next thread as well,
+                // so that the threads write concurrently into the same slots.
+                for value in thread * 5_000..thread * 5_000 + VALUES_PER_THREAD {
+                    array.fetch_insert_with_siphashes13(
+                        value as usize % 4,
+                        value,
+                        Ordering::Relaxed,
+                    );
+                }
+            });
+        }
+    });
+
+    let mut sequential = MinHashArray::<u16, 128, 4>::new();
+    for value in 0..(THREADS + 1) * 5_000 {
+        sequential.insert_with_siphashes13(value as usize % 4, value);
+    }
+
+    assert_eq!(array.into_minhash_array(), sequential);
+}
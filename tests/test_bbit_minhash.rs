@@ -0,0 +1,42 @@
+//! This test module checks that the b-bit MinHash estimates of the Jaccard index
+//! are within the theoretical error bound of the true Jaccard index.
+use minhash_rs::prelude::*;
+
+const PERMUTATIONS: usize = 1024;
+
+/// Checks the b-bit estimates for sets with the provided number of shared elements out of 1000.
+fn check_bbit<const B: usize>(seed: u64, shared: u64) {
+    let offset = seed * 1_000_000;
+    let first: MinHash<u64, PERMUTATIONS> = (offset..offset + 1000).collect();
+    let second: MinHash<u64, PERMUTATIONS> =
+        (offset + 1000 - shared..offset + 2000 - shared).collect();
+
+    let jaccard = shared as f64 / (2000 - shared) as f64;
+    let estimate = BBitMinHash::<PERMUTATIONS, B>::from(&first)
+        .estimate_jaccard_index(&BBitMinHash::<PERMUTATIONS, B>::from(&second));
+
+    // The standard deviation of the fraction of matches, rescaled by the bias correction.
+    let collision = 1.0 / (1 << B) as f64;
+    let expected = jaccard + (1.0 - jaccard) * collision;
+    let std = (expected * (1.0 - expected) / PERMUTATIONS as f64).sqrt() / (1.0 - collision);
+
+    assert!(
+        (estimate - jaccard).abs() <= 5.0 * std,
+        "B: {}, Jaccard: {}, estimate: {}, std: {}",
+        B,
+        jaccard,
+        estimate,
+        std
+    );
+}
+
+#[test]
+pub fn test_bbit_minhash() {
+    for seed in 0..10 {
+        for shared in [0, 200, 500, 800, 1000] {
+            check_bbit::<1>(seed, shared);
+            check_bbit::<2>(seed, shared);
+            check_bbit::<4>(seed, shared);
+        }
+    }
+}
@@ -0,0 +1,37 @@
+//! Test suite checking the bulk Jaccard queries against the per-pair estimates.
+use minhash_rs::prelude::*;
+
+fn signatures() -> (MinHash<u32, 128>, Vec<MinHash<u32, 128>>) {
+    let query: MinHash<u32, 128> = (0..1000_u64).collect();
+    let others = (0..200_u64)
+        .map(|i| ((i * 37) % 1500..(i * 37) % 1500 + 1000).collect())
+        .collect();
+    (query, others)
+}
+
+#[test]
+fn test_estimate_jaccard_index_many() {
+    let (query, others) = signatures();
+    let estimates = query.estimate_jaccard_index_many(&others);
+    assert_eq!(estimates.len(), others.len());
+    for (estimate, other) in estimates.iter().zip(others.iter()) {
+        assert_eq!(*estimate, query.estimate_jaccard_index(other));
+    }
+    assert!(query.estimate_jaccard_index_many(&[]).is_empty());
+}
+
+#[test]
+fn test_top_k_jaccard() {
+    let (query, others) = signatures();
+    let mut expected: Vec<(usize, f64)> = others
+        .iter()
+        .map(|other| query.estimate_jaccard_index(other))
+        .enumerate()
+        .collect();
+    expected.sort_by(|(i, l), (j, r)| r.total_cmp(l).then(i.cmp(j)));
+
+    for k in [0, 1, 5, 50, 200, 500] {
+        let top = query.top_k_jaccard(&others, k);
+        assert_eq!(top, expected[..k.min(others.len())]);
+    }
+}
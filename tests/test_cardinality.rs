@@ -0,0 +1,21 @@
+//! This test module checks that the cardinality estimated from a single MinHash
+//! is within a few percent of the number of distinct elements inserted.
+use minhash_rs::prelude::*;
+
+/// Returns the relative error of the cardinality estimated for the provided number of elements.
+fn relative_error<const PERMUTATIONS: usize>(elements: u64) -> f64 {
+    let minhash: MinHash<u64, PERMUTATIONS> = (0..elements).collect();
+    (minhash.estimate_cardinality() - elements as f64).abs() / elements as f64
+}
+
+#[test]
+pub fn test_cardinality_thousand() {
+    let error = relative_error::<4096>(1_000);
+    assert!(error < 0.05, "Relative error: {}", error);
+}
+
+#[test]
+pub fn test_cardinality_million() {
+    let error = relative_error::<1024>(1_000_000);
+    assert!(error < 0.1, "Relative error: {}", error);
+}
@@ -0,0 +1,28 @@
+//! This test module checks the comparison of MinHashes with a different number of permutations.
+use minhash_rs::prelude::*;
+
+#[test]
+pub fn test_common_prefix_truncation() {
+    let high_resolution: MinHash<u32, 256> = (0..10_000_u64).collect();
+    let truncation = MinHash::<u32, 64>::try_from_slice(&high_resolution.as_ref()[..64]).unwrap();
+    let low_resolution: MinHash<u32, 64> = (0..10_000_u64).collect();
+
+    assert_eq!(truncation, low_resolution);
+    assert_eq!(jaccard_common_prefix(&high_resolution, &truncation), 1.0);
+    assert_eq!(
+        jaccard_common_prefix(&low_resolution, &high_resolution),
+        1.0
+    );
+}
+
+#[test]
+pub fn test_common_prefix_matches_equal_resolution() {
+    let first: MinHash<u64, 256> = (0..1000_u64).collect();
+    let second: MinHash<u64, 64> = (250..1250_u64).collect();
+    let second_high_resolution: MinHash<u64, 256> = (250..1250_u64).collect();
+
+    assert_eq!(
+        jaccard_common_prefix(&first, &second),
+        first.estimate_jaccard_on_prefix(&second_high_resolution, 64)
+    );
+}
@@ -0,0 +1,38 @@
+//! Test suite for the confidence interval of the Jaccard index estimates.
+use minhash_rs::prelude::*;
+
+fn interval<const PERMUTATIONS: usize>() -> (f64, f64, f64) {
+    let first: MinHash<u64, PERMUTATIONS> = (0..2000_u64).collect();
+    let second: MinHash<u64, PERMUTATIONS> = (1000..3000_u64).collect();
+    let (lower, upper) = first.jaccard_confidence_interval(&second, 1.96);
+    let (estimate, _) = first.estimate_jaccard_with_stderr(&second);
+    (lower, estimate, upper)
+}
+
+#[test]
+fn test_confidence_interval_shrinks() {
+    let (small_lower, small_estimate, small_upper) = interval::<64>();
+    let (medium_lower, medium_estimate, medium_upper) = interval::<256>();
+    let (large_lower, large_estimate, large_upper) = interval::<1024>();
+
+    for (lower, estimate, upper) in [
+        (small_lower, small_estimate, small_upper),
+        (medium_lower, medium_estimate, medium_upper),
+        (large_lower, large_estimate, large_upper),
+    ] {
+        assert!(lower <= estimate && estimate <= upper);
+        assert!(0.0 <= lower && upper <= 1.0);
+    }
+
+    assert!(medium_upper - medium_lower < small_upper - small_lower);
+    assert!(large_upper - large_lower < medium_upper - medium_lower);
+}
+
+#[test]
+fn test_confidence_interval_is_clamped() {
+    let minhash: MinHash<u64, 128> = (0..1000_u64).collect();
+    assert_eq!(
+        minhash.jaccard_confidence_interval(&minhash, 3.0),
+        (1.0, 1.0)
+    );
+}
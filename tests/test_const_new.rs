@@ -0,0 +1,18 @@
+//! This test module checks the construction of empty MinHashes in constant contexts.
+use minhash_rs::prelude::*;
+
+const EMPTY: MinHash<u64, 128> = MinHash::new();
+static EMPTY_U8: MinHash<u8, 64> = MinHash::new();
+
+#[test]
+pub fn test_const_new() {
+    assert_eq!(EMPTY, MinHash::<u64, 128>::new());
+    assert_eq!(EMPTY_U8, MinHash::<u8, 64>::default());
+    assert!(EMPTY.is_empty());
+    assert!(EMPTY_U8.is_empty());
+
+    let mut minhash = EMPTY;
+    minhash.insert_with_siphashes13(42);
+    assert!(!minhash.is_empty());
+    assert!(EMPTY.is_empty());
+}
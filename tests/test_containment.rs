@@ -0,0 +1,24 @@
+//! This test module checks that the containment of a strict subset approaches one,
+//! while its Jaccard index is small.
+use minhash_rs::prelude::*;
+
+#[test]
+pub fn test_containment_of_subset() {
+    for seed in 0..20_u64 {
+        let offset = seed * 1_000_000;
+        let subset: MinHash<u64, 1024> = (offset..offset + 1_000).collect();
+        let superset: MinHash<u64, 1024> = (offset..offset + 5_000).collect();
+
+        let jaccard = subset.estimate_jaccard_index(&superset);
+        let containment = subset.estimate_containment(&superset, 1_000.0, 5_000.0);
+        let estimated_containment = subset.estimate_containment_index(&superset);
+
+        assert!(jaccard < 0.25, "Jaccard: {}", jaccard);
+        assert!(containment > 0.75, "Containment: {}", containment);
+        assert!(
+            estimated_containment > 0.7,
+            "Estimated containment: {}",
+            estimated_containment
+        );
+    }
+}
@@ -0,0 +1,42 @@
+//! This test module checks the CSV export and import of MinHashArrays.
+#![cfg(feature = "csv")]
+use minhash_rs::prelude::*;
+
+#[test]
+pub fn test_csv_round_trip() {
+    let mut array = MinHashArray::<u64, 128, 5>::new();
+    for (i, minhash) in array.iter_mut().enumerate().skip(1) {
+        for value in 0..1000_u64 {
+            minhash.insert_with_siphashes13(value * i as u64);
+        }
+    }
+
+    let mut buffer = Vec::new();
+    array.write_csv(&mut buffer).unwrap();
+
+    let text = String::from_utf8(buffer.clone()).unwrap();
+    assert_eq!(text.lines().count(), 5);
+    assert!(text.lines().all(|line| line.split(',').count() == 128));
+
+    let read = MinHashArray::<u64, 128, 5>::read_csv(&mut buffer.as_slice()).unwrap();
+    assert_eq!(read, array);
+}
+
+#[test]
+pub fn test_csv_invalid() {
+    let array = MinHashArray::<u16, 8, 2>::new();
+    let mut buffer = Vec::new();
+    array.write_csv(&mut buffer).unwrap();
+
+    assert!(MinHashArray::<u16, 8, 3>::read_csv(&mut buffer.as_slice()).is_err());
+    assert!(MinHashArray::<u16, 8, 1>::read_csv(&mut buffer.as_slice()).is_err());
+    assert!(MinHashArray::<u16, 4, 2>::read_csv(&mut buffer.as_slice()).is_err());
+    assert!(MinHashArray::<u8, 8, 2>::read_csv(&mut buffer.as_slice()).is_err());
+    assert_eq!(
+        MinHashArray::<u8, 8, 2>::read_csv(&mut buffer.as_slice())
+            .unwrap_err()
+            .kind(),
+        std::io::ErrorKind::InvalidData
+    );
+    assert!(MinHashArray::<u16, 8, 2>::read_csv(&mut buffer.as_slice()).is_ok());
+}
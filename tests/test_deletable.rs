@@ -0,0 +1,53 @@
+//! This test module checks the removal of values from a DeletableMinHash.
+use minhash_rs::prelude::*;
+
+#[test]
+pub fn test_deletable_remove() {
+    let mut deletable = DeletableMinHash::<u64, 128>::new();
+    for value in ["first", "second", "third"] {
+        deletable.insert(value);
+    }
+    for value in ["first", "second", "third"] {
+        assert!(deletable.may_contain(value));
+    }
+
+    assert!(deletable.remove("second") > 0);
+
+    assert!(!deletable.may_contain("second"));
+    assert_eq!(deletable.remove("second"), 0);
+
+    // The words that "second" contributed have lost the minima of the other values,
+    // which are recovered by inserting them again.
+    deletable.insert("first");
+    deletable.insert("third");
+    assert!(deletable.may_contain("first"));
+    assert!(deletable.may_contain("third"));
+    assert!(!deletable.may_contain("second"));
+
+    // Values that were not inserted do not reset any word.
+    assert_eq!(deletable.remove("fourth"), 0);
+
+    // Removing all the values empties the MinHash.
+    deletable.remove("first");
+    deletable.remove("third");
+    assert!(deletable.minhash().is_empty());
+}
+
+#[test]
+pub fn test_deletable_matches_minhash() {
+    let mut deletable = DeletableMinHash::<u16, 256>::new();
+    for value in 0..1000_u64 {
+        deletable.insert(value);
+    }
+    assert_eq!(deletable.minhash(), &(0..1000_u64).collect());
+
+    // Reinserting the remaining values after a removal recovers their MinHash.
+    deletable.remove(500_u64);
+    for value in (0..1000_u64).filter(|value| *value != 500) {
+        deletable.insert(value);
+    }
+    assert_eq!(
+        deletable.minhash(),
+        &(0..1000_u64).filter(|value| *value != 500).collect()
+    );
+}
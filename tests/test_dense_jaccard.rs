@@ -0,0 +1,36 @@
+//! Test suite checking the Jaccard estimate ignoring the words unset in both MinHashes.
+use minhash_rs::prelude::*;
+
+#[test]
+fn test_dense_jaccard_on_tiny_sets() {
+    let mut plain_error = 0.0;
+    let mut dense_error = 0.0;
+    for value in 0..200_u64 {
+        // Two disjoint singletons, whose true Jaccard index is zero: with 8 bit
+        // words, the permutations where both elements hash to the maximal value
+        // are still unset in both MinHashes.
+        let mut first = MinHash::<u8, 4096>::new();
+        let mut second = MinHash::<u8, 4096>::new();
+        first.insert_with_siphashes13(value);
+        second.insert_with_siphashes13(value + 1_000_000);
+
+        plain_error += first.estimate_jaccard_index(&second);
+        dense_error += first.estimate_jaccard_index_dense(&second);
+    }
+    assert!(
+        dense_error < plain_error,
+        "Dense error: {}, plain error: {}",
+        dense_error,
+        plain_error
+    );
+}
+
+#[test]
+fn test_dense_jaccard_matches_plain_on_wide_words() {
+    let first: MinHash<u64, 256> = (0..1000_u64).collect();
+    let second: MinHash<u64, 256> = (500..1500_u64).collect();
+    assert_eq!(
+        first.estimate_jaccard_index_dense(&second),
+        first.estimate_jaccard_index(&second)
+    );
+}
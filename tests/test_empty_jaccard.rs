@@ -0,0 +1,102 @@
+//! Test suite checking the Jaccard index estimated for empty MinHashes.
+use minhash_rs::prelude::*;
+
+#[test]
+fn test_empty_versus_empty() {
+    assert!(MinHash::<u64, 128>::new()
+        .estimate_jaccard_index(&MinHash::new())
+        .is_nan());
+    assert!(MinHash::<u8, 16>::new()
+        .estimate_jaccard_index(&MinHash::new())
+        .is_nan());
+}
+
+#[test]
+fn test_empty_versus_non_empty() {
+    // With 8 bit words, some of the words of a small set are still maximal,
+    // and would match the words of the empty MinHash.
+    for value in 0..100_u64 {
+        let mut minhash = MinHash::<u8, 1024>::new();
+        minhash.insert_with_siphashes13(value);
+        assert_eq!(minhash.estimate_jaccard_index(&MinHash::new()), 0.0);
+        assert_eq!(MinHash::new().estimate_jaccard_index(&minhash), 0.0);
+    }
+}
+
+#[test]
+fn test_non_empty_is_unchanged() {
+    let first: MinHash<u32, 128> = (0..1000_u64).collect();
+    let second: MinHash<u32, 128> = (500..1500_u64).collect();
+    assert_eq!(
+        first.estimate_jaccard_index(&second),
+        first.matching_words(&second) as f64 / 128.0
+    );
+    assert_eq!(first.estimate_jaccard_index(&first), 1.0);
+}
+
+#[test]
+fn test_knn_graph_with_empty_minhashes() {
+    let mut array = MinHashArray::<u64, 128, 4>::new();
+    array[0] = (0..1000_u64).collect();
+    array[1] = (100..1100_u64).collect();
+
+    let graph = array.knn_graph(3);
+
+    // The two empty MinHashes are not each other's neighbours.
+    assert_eq!(graph[2], vec![(0, 0.0), (1, 0.0)]);
+    assert_eq!(graph[3], vec![(0, 0.0), (1, 0.0)]);
+    assert_eq!(graph[0][0].0, 1);
+    assert!(graph
+        .iter()
+        .flatten()
+        .all(|(_, similarity)| !similarity.is_nan()));
+}
+
+#[test]
+fn test_similarity_histogram_with_empty_minhashes() {
+    let mut array = MinHashArray::<u64, 128, 4>::new();
+    array[0] = (0..1000_u64).collect();
+    array[1] = (0..1000_u64).collect();
+
+    let histogram = array.similarity_histogram(10);
+
+    // Of the six pairs, the one of the two empty MinHashes is not counted,
+    // the four pairs of an empty and a non-empty MinHash are in the first
+    // bucket, and the pair of identical MinHashes is in the last one.
+    assert_eq!(histogram.iter().sum::<usize>(), 5);
+    assert_eq!(histogram[0], 4);
+    assert_eq!(histogram[9], 1);
+}
+
+#[test]
+fn test_top_k_jaccard_with_empty_minhashes() {
+    let query: MinHash<u8, 256> = (0..10_u64).collect();
+    let others = [MinHash::new(), (0..10_u64).collect(), (5..15_u64).collect()];
+
+    let top = query.top_k_jaccard(&others, 3);
+
+    // The empty MinHash is ranked last, with the same Jaccard index as the plain estimate.
+    assert_eq!(top[0], (1, 1.0));
+    assert_eq!(top[2], (0, 0.0));
+    for (index, jaccard) in top {
+        assert_eq!(jaccard, query.estimate_jaccard_index(&others[index]));
+    }
+
+    // The Jaccard index of two empty MinHashes is undefined, and they are skipped.
+    let top = MinHash::<u8, 256>::new().top_k_jaccard(&others, 3);
+    assert_eq!(top, vec![(1, 0.0), (2, 0.0)]);
+}
+
+#[test]
+fn test_set_cardinalities_of_empty_minhashes() {
+    let empty = MinHash::<u64, 128>::new();
+    let minhash: MinHash<u64, 128> = (0..1000_u64).collect();
+
+    assert_eq!(empty.estimate_intersection_cardinality(&empty), 0.0);
+    assert_eq!(empty.estimate_symmetric_difference_cardinality(&empty), 0.0);
+    assert_eq!(empty.estimate_intersection_cardinality(&minhash), 0.0);
+    assert_eq!(
+        empty.estimate_symmetric_difference_cardinality(&minhash),
+        minhash.estimate_cardinality()
+    );
+}
@@ -0,0 +1,46 @@
+//! Test suite for the evaluation helpers.
+use std::collections::HashSet;
+
+use minhash_rs::eval::{exact_jaccard, jaccard_error};
+use minhash_rs::prelude::*;
+
+#[test]
+fn test_exact_jaccard_disjoint() {
+    let first: HashSet<u64> = (0..100).collect();
+    let second: HashSet<u64> = (100..300).collect();
+    assert_eq!(exact_jaccard(&first, &second), 0.0);
+    assert_eq!(exact_jaccard(&first, &HashSet::new()), 0.0);
+}
+
+#[test]
+fn test_exact_jaccard_identical() {
+    let first: HashSet<u64> = (0..100).collect();
+    assert_eq!(exact_jaccard(&first, &first.clone()), 1.0);
+    assert!(exact_jaccard::<u64>(&HashSet::new(), &HashSet::new()).is_nan());
+}
+
+#[test]
+fn test_exact_jaccard_partial_overlap() {
+    let first: HashSet<u64> = (0..100).collect();
+    let second: HashSet<u64> = (50..300).collect();
+    assert_eq!(exact_jaccard(&first, &second), 50.0 / 300.0);
+    assert_eq!(
+        exact_jaccard(&first, &second),
+        exact_jaccard(&second, &first)
+    );
+
+    let first_minhash: MinHash<u64, 512> = first.iter().collect();
+    let second_minhash: MinHash<u64, 512> = second.iter().collect();
+    let error = jaccard_error(
+        first_minhash.estimate_jaccard_index(&second_minhash),
+        exact_jaccard(&first, &second),
+    );
+    assert!(error < 0.06, "Error: {}", error);
+}
+
+#[test]
+fn test_jaccard_error() {
+    assert_eq!(jaccard_error(0.5, 0.5), 0.0);
+    assert_eq!(jaccard_error(0.0, 1.0), 1.0);
+    assert_eq!(jaccard_error(1.0, 0.0), 1.0);
+}
@@ -0,0 +1,28 @@
+//! Test suite for the construction of a MinHash from explicit minima.
+use minhash_rs::prelude::*;
+
+#[test]
+fn test_from_minima_round_trip() {
+    let minhash: MinHash<u32, 128> = (0..1000_u64).collect();
+    let minima: Vec<u32> = minhash.iter().copied().collect();
+
+    assert_eq!(MinHash::<u32, 128>::from_minima(minima), minhash);
+    assert_eq!(
+        MinHash::<u32, 128>::from_minima(minhash.iter().copied()),
+        minhash
+    );
+}
+
+#[test]
+#[should_panic(expected = "More minima than the number of permutations (4) were provided.")]
+fn test_from_minima_too_many() {
+    MinHash::<u8, 4>::from_minima([1, 2, 3, 4, 5]);
+}
+
+#[test]
+#[should_panic(
+    expected = "The number of minima (3) must be equal to the number of permutations (4)."
+)]
+fn test_from_minima_too_few() {
+    MinHash::<u8, 4>::from_minima([1, 2, 3]);
+}
@@ -0,0 +1,43 @@
+//! This test module checks the insertion and query with a hash family selected at runtime.
+use minhash_rs::prelude::*;
+
+#[test]
+pub fn test_hash_family_round_trip() {
+    for family in [HashFamily::Sip13, HashFamily::Fnv] {
+        let mut minhash = MinHash::<u32, 128>::new();
+        for value in 0..100_u64 {
+            minhash.insert_with(value, family);
+        }
+        for value in 0..100_u64 {
+            assert!(minhash.may_contain_value_with(value, family));
+        }
+    }
+}
+
+#[test]
+pub fn test_hash_family_matches_methods() {
+    let mut sip13 = MinHash::<u64, 128>::new();
+    let mut fnv = MinHash::<u64, 128>::new();
+    for value in 0..1000_u64 {
+        sip13.insert_with(value, HashFamily::Sip13);
+        fnv.insert_with(value, HashFamily::Fnv);
+    }
+
+    assert_eq!(sip13, (0..1000_u64).collect());
+    let mut expected_fnv = MinHash::<u64, 128>::new();
+    for value in 0..1000_u64 {
+        expected_fnv.insert_with_fvn(value);
+    }
+    assert_eq!(fnv, expected_fnv);
+}
+
+#[test]
+pub fn test_hash_family_mixing() {
+    let mut minhash = MinHash::<u64, 128>::new();
+    minhash.insert_with(42, HashFamily::Sip13);
+    assert!(!minhash.may_contain_value_with(42, HashFamily::Fnv));
+
+    let mut minhash = MinHash::<u64, 128>::new();
+    minhash.insert_with(42, HashFamily::Fnv);
+    assert!(!minhash.may_contain_value_with(42, HashFamily::Sip13));
+}
@@ -0,0 +1,29 @@
+//! Test suite comparing the cardinality estimates of the HyperLogLog paired with
+//! the MinHash against the ones of the MinHash alone.
+#![cfg(feature = "hll")]
+use hyperloglog_rs::prelude::Precision14;
+use minhash_rs::prelude::*;
+
+#[test]
+fn test_hll_cardinality_accuracy() {
+    const CARDINALITY: u64 = 1_000_000;
+    let paired: MinHashWithCardinality<u32, 128, Precision14, 6> = (0..CARDINALITY).collect();
+
+    let hll_error = (paired.estimate_cardinality() - CARDINALITY as f64).abs() / CARDINALITY as f64;
+    let minhash_error =
+        (paired.minhash().estimate_cardinality() - CARDINALITY as f64).abs() / CARDINALITY as f64;
+
+    // The relative standard error is about 0.8% for the HyperLogLog with 2^14
+    // registers and about 9% for the MinHash with 128 permutations.
+    assert!(
+        hll_error < 0.03,
+        "HyperLogLog relative error: {}",
+        hll_error
+    );
+    assert!(
+        hll_error < minhash_error,
+        "HyperLogLog relative error: {}, MinHash relative error: {}",
+        hll_error,
+        minhash_error
+    );
+}
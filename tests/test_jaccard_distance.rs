@@ -0,0 +1,31 @@
+//! This test module checks the estimated Jaccard distance.
+use minhash_rs::prelude::*;
+
+#[test]
+pub fn test_jaccard_distance() {
+    let first: MinHash<u32, 512> = (0..1000_u64).collect();
+    let identical: MinHash<u32, 512> = (0..1000_u64).collect();
+    let disjoint: MinHash<u32, 512> = (1_000_000..1_001_000_u64).collect();
+
+    assert_eq!(first.estimate_jaccard_distance(&identical), 0.0);
+    assert!(first.estimate_jaccard_distance(&disjoint) > 0.99);
+    assert_eq!(first.estimate_jaccard_distance(&MinHash::new()), 1.0);
+}
+
+#[test]
+pub fn test_jaccard_distance_triangle_inequality() {
+    let minhashes: Vec<MinHash<u16, 128>> = (0..10_u64)
+        .map(|i| (i * 200..i * 200 + 1000).collect())
+        .collect();
+
+    for a in minhashes.iter() {
+        for b in minhashes.iter() {
+            for c in minhashes.iter() {
+                assert!(
+                    a.estimate_jaccard_distance(c)
+                        <= a.estimate_jaccard_distance(b) + b.estimate_jaccard_distance(c) + 1e-12
+                );
+            }
+        }
+    }
+}
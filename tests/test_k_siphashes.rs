@@ -0,0 +1,67 @@
+//! Test suite comparing the accuracy of the MinHash seeded by one and by several hashers.
+use std::collections::HashSet;
+
+use minhash_rs::prelude::*;
+
+/// Return set with up to the provided number of elements.
+fn populate_set(elements: usize, mut random_state: u64) -> HashSet<u64> {
+    random_state = random_state.splitmix();
+
+    (0..elements)
+        .map(|_| {
+            random_state = random_state.xorshift();
+            random_state % (2 * elements) as u64
+        })
+        .collect()
+}
+
+/// Returns the mean squared error of the Jaccard index estimated with `K` groups,
+/// and the mean variance `J(1 - J) / PERMUTATIONS` of independent permutations.
+fn mean_squared_error<const K: usize>() -> (f64, f64) {
+    const PAIRS: usize = 1000;
+    let mut error = 0.0;
+    let mut variance = 0.0;
+    for pair in 0..PAIRS {
+        let first_set = populate_set(500, 4567 * (pair as u64 + 1));
+        let second_set = populate_set(500, 47325567 * (pair as u64 + 1));
+        let ground_truth = first_set.intersection(&second_set).count() as f64
+            / first_set.union(&second_set).count() as f64;
+
+        let mut first = MinHash::<u32, 128>::new();
+        let mut second = MinHash::<u32, 128>::new();
+        for value in first_set.iter() {
+            first.insert_with_k_siphashes13::<K, _>(value);
+        }
+        for value in second_set.iter() {
+            second.insert_with_k_siphashes13::<K, _>(value);
+        }
+        error += (first.estimate_jaccard_index(&second) - ground_truth).powi(2);
+        variance += ground_truth * (1.0 - ground_truth) / 128.0;
+    }
+    (error / PAIRS as f64, variance / PAIRS as f64)
+}
+
+#[test]
+fn test_k_siphashes_jaccard_variance() {
+    let (single, variance) = mean_squared_error::<1>();
+    let (grouped, _) = mean_squared_error::<4>();
+    // The variance of independent permutations is the floor that seeding can reach:
+    // correlated permutations would inflate the error above it. Both schemes must be
+    // at the floor, up to the sampling error over the pairs, and the grouped seeds
+    // must not be worse than the single one.
+    for (name, error) in [("single", single), ("grouped", grouped)] {
+        assert!(
+            (error - variance).abs() <= 0.15 * variance,
+            "The mean squared error of the {} seed ({}) should match the variance of independent permutations ({}).",
+            name,
+            error,
+            variance
+        );
+    }
+    assert!(
+        grouped <= single + 0.05 * variance,
+        "The mean squared error with four seeds ({}) should not exceed the one with a single seed ({}).",
+        grouped,
+        single
+    );
+}
@@ -0,0 +1,62 @@
+//! This test module checks that the LSH index recovers near-duplicate MinHashes with high recall.
+use minhash_rs::prelude::*;
+
+#[test]
+pub fn test_lsh_recall() {
+    const SIGNATURES: u64 = 300;
+
+    let mut index = LshIndex::<u64, u64, 128, 32, 4>::new();
+    let mut queries = Vec::new();
+
+    for i in 0..SIGNATURES {
+        let offset = i * 1_000;
+        // The original and the near-duplicate share 95 of their 100 elements,
+        // for a Jaccard index of about 0.9.
+        let original: MinHash<u64, 128> = (offset..offset + 100).collect();
+        let duplicate: MinHash<u64, 128> = (offset + 5..offset + 105).collect();
+        assert!(original.estimate_jaccard_index(&duplicate) > 0.8);
+        index.insert(i, &original);
+        queries.push(duplicate);
+    }
+
+    let recovered = queries
+        .iter()
+        .enumerate()
+        .filter(|(i, duplicate)| index.query(duplicate).contains(&(*i as u64)))
+        .count();
+
+    assert!(
+        recovered as f64 / SIGNATURES as f64 > 0.95,
+        "Only {} near-duplicates out of {} were recovered.",
+        recovered,
+        SIGNATURES
+    );
+}
+
+#[test]
+pub fn test_cross_similarity_join() {
+    let mut left = MinHashArray::<u64, 128, 50>::new();
+    let mut right = MinHashArray::<u64, 128, 40>::new();
+
+    for i in 0..50 {
+        let offset = i as u64 * 1_000;
+        left[i] = (offset..offset + 100).collect();
+    }
+    for j in 0..40 {
+        let offset = 1_000_000 + j as u64 * 1_000;
+        right[j] = (offset..offset + 100).collect();
+    }
+
+    // We plant a near-duplicate of some of the left MinHashes in the right array.
+    let planted = [(3, 7), (11, 0), (25, 39), (42, 18), (49, 21)];
+    for &(i, j) in planted.iter() {
+        let offset = i as u64 * 1_000;
+        right[j] = (offset + 5..offset + 105).collect();
+    }
+
+    let pairs = left.cross_similarity_join::<32, 4, 40>(&right, 0.7);
+    let found: Vec<(usize, usize)> = pairs.iter().map(|&(i, j, _)| (i, j)).collect();
+
+    assert_eq!(found, planted);
+    assert!(pairs.iter().all(|&(_, _, jaccard)| jaccard >= 0.7));
+}
@@ -0,0 +1,22 @@
+//! Test suite checking the number of matching words between MinHashes.
+use minhash_rs::prelude::*;
+
+#[test]
+fn test_matching_words_handcrafted() {
+    let signatures = [
+        [0_u16, 1, 2, 3, 4, 5, 6, 7],
+        [0_u16, 9, 2, 9, 4, 9, 6, 9],
+        [9_u16, 9, 9, 9, 9, 9, 9, 9],
+        [0_u16, 1, 2, 3, 9, 9, 9, 9],
+    ];
+    for first in signatures {
+        for second in signatures {
+            let expected = (0..8).filter(|&i| first[i] == second[i]).count();
+            let first = MinHash::from_words(first);
+            let second = MinHash::from_words(second);
+            assert_eq!(first.matching_words(&second), expected);
+            assert_eq!(second.matching_words(&first), expected);
+            assert_eq!(first.estimate_jaccard_index(&second), expected as f64 / 8.0);
+        }
+    }
+}
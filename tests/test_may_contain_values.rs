@@ -0,0 +1,31 @@
+//! This test module checks the batch membership queries.
+use minhash_rs::prelude::*;
+
+fn check_may_contain_values<Word, const PERMUTATIONS: usize>()
+where
+    Word: Min + XorShift + Copy + Eq + Maximal,
+    u64: Primitive<Word>,
+{
+    let minhash: MinHash<Word, PERMUTATIONS> = (0..50_u64).collect();
+    let values: Vec<u64> = (0..2000).collect();
+
+    let expected: Vec<bool> = values
+        .iter()
+        .map(|value| minhash.may_contain_value_with_siphashes13(value))
+        .collect();
+
+    assert_eq!(
+        minhash.may_contain_values_with_siphashes13(&values),
+        expected
+    );
+    assert!(expected[..50].iter().all(|present| *present));
+}
+
+#[test]
+pub fn test_may_contain_values() {
+    check_may_contain_values::<u8, 16>();
+    check_may_contain_values::<u16, 100>();
+    check_may_contain_values::<u32, 128>();
+    check_may_contain_values::<u64, 1000>();
+    check_may_contain_values::<u64, 7>();
+}
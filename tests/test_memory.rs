@@ -0,0 +1,24 @@
+//! Test suite checking the memory accounting of the MinHash.
+use minhash_rs::prelude::*;
+
+fn check_memory<Word: Maximal, const PERMUTATIONS: usize>() {
+    let minhash = MinHash::<Word, PERMUTATIONS>::new();
+    assert_eq!(minhash.memory(), MinHash::<Word, PERMUTATIONS>::MEMORY_BITS);
+    assert_eq!(
+        MinHash::<Word, PERMUTATIONS>::MEMORY_BITS,
+        MinHash::<Word, PERMUTATIONS>::MEMORY_BYTES * 8
+    );
+    assert_eq!(
+        MinHash::<Word, PERMUTATIONS>::stack_size(),
+        PERMUTATIONS * core::mem::size_of::<Word>()
+    );
+}
+
+#[test]
+fn test_memory() {
+    check_memory::<u8, 16>();
+    check_memory::<u16, 32>();
+    check_memory::<u32, 64>();
+    check_memory::<u64, 128>();
+    check_memory::<u128, 7>();
+}
@@ -0,0 +1,39 @@
+//! This test module checks the insertion of values into the MinHashes of a MinHashArray.
+use minhash_rs::prelude::*;
+
+#[test]
+pub fn test_minhash_array_insert_slots() {
+    let mut array = MinHashArray::<u32, 256, 4>::new();
+    for value in 0..4000_u64 {
+        array.insert_with_siphashes13(value as usize % 4, value);
+    }
+
+    for i in 0..4 {
+        let expected: MinHash<u32, 256> = (0..4000_u64)
+            .filter(|value| *value as usize % 4 == i)
+            .collect();
+        assert_eq!(array[i], expected);
+        for j in 0..4 {
+            let jaccard = array[i].estimate_jaccard_index(&array[j]);
+            if i == j {
+                assert_eq!(jaccard, 1.0);
+            } else {
+                assert!(jaccard < 0.05, "{}", jaccard);
+            }
+        }
+    }
+}
+
+#[test]
+pub fn test_minhash_array_insert_all() {
+    let mut array = MinHashArray::<u16, 128, 3>::new();
+    for value in 0..1000_u64 {
+        array.insert_all(value);
+    }
+    array.insert_with_siphashes13(2, 5000_u64);
+
+    let expected: MinHash<u16, 128> = (0..1000_u64).collect();
+    assert_eq!(array[0], expected);
+    assert_eq!(array[1], expected);
+    assert!(array[0].estimate_jaccard_index(&array[2]) > 0.95);
+}
@@ -0,0 +1,40 @@
+//! This test module checks MinHashes of different types stored as trait objects.
+use minhash_rs::prelude::*;
+
+#[test]
+pub fn test_minhash_like_registry() {
+    let small_first: MinHash<u8, 128> = (0..1000_u64).collect();
+    let small_second: MinHash<u8, 128> = (0..1000_u64).collect();
+    let large_first: MinHash<u64, 128> = (0..1000_u64).collect();
+    let large_second: MinHash<u64, 128> = (500..1500_u64).collect();
+    let other_permutations: MinHash<u64, 64> = (0..1000_u64).collect();
+
+    let registry: Vec<Box<dyn MinHashLike>> = vec![
+        Box::new(small_first),
+        Box::new(small_second),
+        Box::new(large_first),
+        Box::new(large_second),
+        Box::new(other_permutations),
+    ];
+
+    assert_eq!(
+        registry[0].estimate_jaccard_index(registry[1].as_ref()),
+        1.0
+    );
+    assert_eq!(
+        registry[2].estimate_jaccard_index(registry[3].as_ref()),
+        large_first.estimate_jaccard_index(&large_second)
+    );
+
+    // Different word types or numbers of permutations are not comparable.
+    assert!(registry[0]
+        .estimate_jaccard_index(registry[2].as_ref())
+        .is_nan());
+    assert!(registry[2]
+        .estimate_jaccard_index(registry[4].as_ref())
+        .is_nan());
+
+    assert_eq!(registry[0].memory(), 128 * 8);
+    assert_eq!(registry[2].memory(), 128 * 64);
+    assert_eq!(registry[2].fill_fraction(), 1.0);
+}
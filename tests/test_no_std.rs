@@ -0,0 +1,23 @@
+//! This test module checks that the MinHash can be constructed and queried
+//! with only the `core` library in scope.
+#![no_std]
+
+use minhash_rs::prelude::*;
+
+#[test]
+pub fn test_no_std() {
+    let mut first = MinHash::<u64, 64>::new();
+    let mut second = MinHash::<u64, 64>::new();
+
+    for i in 0..100_u64 {
+        first.insert_with_siphashes13(i);
+        second.insert_with_siphashes13(i + 50);
+    }
+
+    assert!(first.may_contain_value_with_siphashes13(42));
+    assert!(!first.is_empty());
+
+    let approximation = first.estimate_jaccard_index(&second);
+
+    assert!((approximation - 50.0 / 150.0).abs() < 0.2);
+}
@@ -0,0 +1,39 @@
+//! This test module checks the accuracy and the memory of the 1-bit MinHash.
+use minhash_rs::prelude::*;
+
+#[test]
+pub fn test_one_bit_minhash_accuracy() {
+    for (start, expected) in [(0_u64, 1.0), (250, 0.6), (500, 1.0 / 3.0), (1000, 0.0)] {
+        let first: MinHash<u64, 8192> = (0..1000_u64).collect();
+        let second: MinHash<u64, 8192> = (start..start + 1000).collect();
+
+        let first_one_bit = OneBitMinHash::<8192, 128>::from(&first);
+        let second_one_bit = OneBitMinHash::<8192, 128>::from(&second);
+
+        let full = first.estimate_jaccard_index(&second);
+        let one_bit = first_one_bit.estimate_jaccard_index(&second_one_bit);
+
+        assert!((full - expected).abs() < 0.03, "{} {}", full, expected);
+        assert!(
+            (one_bit - expected).abs() < 0.05,
+            "{} {}",
+            one_bit,
+            expected
+        );
+        assert_eq!(
+            one_bit,
+            BBitMinHash::<8192, 1>::from(&first)
+                .estimate_jaccard_index(&BBitMinHash::<8192, 1>::from(&second))
+        );
+    }
+}
+
+#[test]
+pub fn test_one_bit_minhash_memory() {
+    let minhash: MinHash<u32, 256> = (0..1000_u64).collect();
+    let one_bit = OneBitMinHash::<256, 4>::from(&minhash);
+
+    assert_eq!(one_bit.number_of_permutations(), 256);
+    assert_eq!(one_bit.memory(), 256);
+    assert_eq!(core::mem::size_of::<OneBitMinHash<256, 4>>(), 256 / 8);
+}
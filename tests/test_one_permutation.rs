@@ -0,0 +1,46 @@
+//! This test module checks that the densified one-permutation MinHash estimates
+//! the Jaccard index with an accuracy comparable to the one of the MinHash.
+use minhash_rs::prelude::*;
+
+#[test]
+pub fn test_one_permutation_accuracy() {
+    const PAIRS: u64 = 100;
+
+    let mut minhash_error = 0.0;
+    let mut one_permutation_error = 0.0;
+
+    for i in 0..PAIRS {
+        let offset = i * 1_000_000;
+        // The sizes of the sets range from sparse (many empty bins) to dense.
+        let size = 20 + i * 10;
+        let shift = size / 3;
+        let jaccard = (size - shift) as f64 / (size + shift) as f64;
+
+        let mut first = OnePermutationMinHash::<u64, 128>::new();
+        let mut second = OnePermutationMinHash::<u64, 128>::new();
+        for value in offset..offset + size {
+            first.insert(value);
+            second.insert(value + shift);
+        }
+        let first_minhash: MinHash<u64, 128> = (offset..offset + size).collect();
+        let second_minhash: MinHash<u64, 128> = (offset + shift..offset + size + shift).collect();
+
+        one_permutation_error += (first.estimate_jaccard_index(&second) - jaccard).abs();
+        minhash_error += (first_minhash.estimate_jaccard_index(&second_minhash) - jaccard).abs();
+    }
+
+    one_permutation_error /= PAIRS as f64;
+    minhash_error /= PAIRS as f64;
+
+    assert!(
+        one_permutation_error < 0.06,
+        "Error: {}",
+        one_permutation_error
+    );
+    assert!(
+        one_permutation_error < 1.5 * minhash_error,
+        "One permutation error: {}, MinHash error: {}",
+        one_permutation_error,
+        minhash_error
+    );
+}
@@ -0,0 +1,23 @@
+//! This test module checks the streaming accumulation of the union of MinHashes.
+use minhash_rs::prelude::*;
+
+#[test]
+pub fn test_online_matches_union_all() {
+    let minhashes: Vec<MinHash<u32, 256>> = (0..10_u64)
+        .map(|i| (i * 700..i * 700 + 1000).collect())
+        .collect();
+
+    let mut online = OnlineMinHash::<u32, 256>::new();
+    assert_eq!(online.count(), 0);
+    assert!(online.union().is_empty());
+
+    for (i, minhash) in minhashes.iter().enumerate() {
+        online.push(minhash);
+        assert_eq!(online.count(), i + 1);
+        assert_eq!(online.union(), &minhashes[..=i].iter().copied().union_all());
+    }
+
+    let full: MinHash<u32, 256> = (0..9 * 700 + 1000_u64).collect();
+    assert_eq!(online.jaccard_to(&full), 1.0);
+    assert_eq!(online.into_union(), minhashes.into_iter().union_all());
+}
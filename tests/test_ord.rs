@@ -0,0 +1,34 @@
+//! This test module checks the total order of MinHashes.
+use minhash_rs::prelude::*;
+use std::collections::BTreeMap;
+
+#[test]
+pub fn test_ord_sort() {
+    let minhashes: Vec<MinHash<u16, 32>> = (0..20_u64)
+        .map(|i| (i * 10..i * 10 + 50).collect())
+        .collect();
+
+    let mut sorted = minhashes.clone();
+    sorted.sort();
+    let mut reverse_sorted: Vec<MinHash<u16, 32>> = minhashes.iter().rev().copied().collect();
+    reverse_sorted.sort();
+
+    assert_eq!(sorted, reverse_sorted);
+    for window in sorted.windows(2) {
+        assert!(window[0] < window[1]);
+        assert!(window[0].as_ref() < window[1].as_ref());
+    }
+}
+
+#[test]
+pub fn test_ord_btree_map() {
+    let mut map = BTreeMap::new();
+    for i in 0..20_u64 {
+        let minhash: MinHash<u64, 16> = (i * 10..i * 10 + 50).collect();
+        map.insert(minhash, i);
+    }
+    for i in 0..20_u64 {
+        let minhash: MinHash<u64, 16> = (i * 10..i * 10 + 50).collect();
+        assert_eq!(map.get(&minhash), Some(&i));
+    }
+}
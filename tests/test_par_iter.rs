@@ -0,0 +1,20 @@
+//! This test module checks that the MinHash built from a parallel iterator
+//! is identical to the one built sequentially, regardless of the number of threads.
+#![cfg(feature = "rayon")]
+use minhash_rs::prelude::*;
+use rayon::prelude::*;
+
+#[test]
+pub fn test_from_par_iter() {
+    let sequential: MinHash<u64, 128> = (0..1_000_000_u64).collect();
+
+    for threads in [1, 2, 4, 7] {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .unwrap();
+        let parallel: MinHash<u64, 128> =
+            pool.install(|| (0..1_000_000_u64).into_par_iter().collect());
+        assert_eq!(parallel, sequential, "Mismatch with {} threads.", threads);
+    }
+}
@@ -0,0 +1,71 @@
+//! This test module checks that the permutations used by the MinHash are independent,
+//! by comparing the mean absolute error of the Jaccard index estimated by the MinHash
+//! against the one obtained by the previous scheme, which derived all the permutations
+//! from a single XorShift chain.
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+
+use minhash_rs::prelude::*;
+use siphasher::sip128::SipHasher13;
+
+/// Return set with up to the provided number of elements.
+fn populate_set(elements: usize, mut random_state: u64) -> HashSet<u64> {
+    random_state = random_state.splitmix();
+
+    (0..elements)
+        .map(|_| {
+            random_state = random_state.xorshift();
+            random_state % (2 * elements) as u64
+        })
+        .collect()
+}
+
+/// Returns the MinHash words of the provided set using a single XorShift chain per element,
+/// where each 16 bits word is obtained by truncating the 32 bits XorShift of the previous one.
+fn xorshift_chain_minhash<const PERMUTATIONS: usize>(set: &HashSet<u64>) -> [u16; PERMUTATIONS] {
+    let mut words = [u16::MAX; PERMUTATIONS];
+    for value in set {
+        let mut hasher = SipHasher13::new();
+        value.hash(&mut hasher);
+        let mut hash: u16 = hasher.finish().splitmix().splitmix() as u16;
+        for word in words.iter_mut() {
+            hash = (hash as u32).xorshift() as u16;
+            *word = (*word).min(hash);
+        }
+    }
+    words
+}
+
+#[test]
+pub fn test_permutations_reduce_jaccard_error() {
+    const PERMUTATIONS: usize = 128;
+    const PAIRS: usize = 200;
+
+    let mut xorshift_error = 0.0;
+    let mut independent_error = 0.0;
+
+    for pair in 0..PAIRS {
+        let first_set = populate_set(1000, 4567 * (pair as u64 + 1));
+        let second_set = populate_set(1000, 47325567 * (pair as u64 + 1));
+        let ground_truth = first_set.intersection(&second_set).count() as f64
+            / first_set.union(&second_set).count() as f64;
+
+        let first: MinHash<u16, PERMUTATIONS> = first_set.iter().collect();
+        let second: MinHash<u16, PERMUTATIONS> = second_set.iter().collect();
+        independent_error += (first.estimate_jaccard_index(&second) - ground_truth).abs();
+
+        let first = MinHash::from_words(xorshift_chain_minhash::<PERMUTATIONS>(&first_set));
+        let second = MinHash::from_words(xorshift_chain_minhash::<PERMUTATIONS>(&second_set));
+        xorshift_error += (first.estimate_jaccard_index(&second) - ground_truth).abs();
+    }
+
+    xorshift_error /= PAIRS as f64;
+    independent_error /= PAIRS as f64;
+
+    assert!(
+        independent_error < xorshift_error,
+        "The mean absolute error with independent permutations ({}) should be smaller than the one of the XorShift chain ({}).",
+        independent_error,
+        xorshift_error
+    );
+}
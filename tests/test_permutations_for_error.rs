@@ -0,0 +1,44 @@
+//! This test module checks the choice of the number of permutations for a target error.
+use minhash_rs::prelude::*;
+
+#[test]
+pub fn test_permutations_for_error() {
+    assert_eq!(permutations_for_error(0.01), 2500);
+    assert_eq!(permutations_for_error(0.05), 100);
+    assert_eq!(permutations_for_error(1.0), 1);
+
+    for target in [0.001, 0.005, 0.01, 0.02, 0.1, 0.3] {
+        let permutations = permutations_for_error(target);
+        // The number of permutations is sufficient for any Jaccard index...
+        for jaccard in [0.0, 0.1, 0.25, 0.5, 0.75, 1.0] {
+            assert!(expected_std_error(permutations, jaccard) <= target);
+        }
+        // ...and it is the smallest one for the worst case.
+        if permutations > 1 {
+            assert!(expected_std_error(permutations - 1, 0.5) > target);
+        }
+    }
+}
+
+#[test]
+pub fn test_expected_std_error_empirical() {
+    // The standard deviation of the estimates over many pairs of sets matches the expected one.
+    let estimates: Vec<f64> = (0..200_u64)
+        .map(|i| {
+            let first: MinHash<u64, 100> = (i * 100_000..i * 100_000 + 1000).collect();
+            let second: MinHash<u64, 100> = (i * 100_000 + 333..i * 100_000 + 1333).collect();
+            first.estimate_jaccard_index(&second)
+        })
+        .collect();
+    let mean = estimates.iter().sum::<f64>() / estimates.len() as f64;
+    let variance =
+        estimates.iter().map(|e| (e - mean).powi(2)).sum::<f64>() / (estimates.len() - 1) as f64;
+
+    let expected = expected_std_error(100, 0.5);
+    assert!(
+        (variance.sqrt() - expected).abs() < 0.015,
+        "{} {}",
+        variance.sqrt(),
+        expected
+    );
+}
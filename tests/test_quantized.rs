@@ -0,0 +1,60 @@
+//! Test suite measuring the accuracy loss of the quantized MinHash.
+use std::collections::HashSet;
+
+use minhash_rs::prelude::*;
+
+/// Return set with up to the provided number of elements.
+fn populate_set(elements: usize, mut random_state: u64) -> HashSet<u64> {
+    random_state = random_state.splitmix();
+
+    (0..elements)
+        .map(|_| {
+            random_state = random_state.xorshift();
+            random_state % (2 * elements) as u64
+        })
+        .collect()
+}
+
+#[test]
+fn test_quantized_accuracy_loss() {
+    const PAIRS: usize = 200;
+    let mut full_error = 0.0;
+    let mut quantized_error = 0.0;
+
+    for pair in 0..PAIRS {
+        let first_set = populate_set(1000, 4567 * (pair as u64 + 1));
+        let second_set = populate_set(1000, 47325567 * (pair as u64 + 1));
+        let ground_truth = first_set.intersection(&second_set).count() as f64
+            / first_set.union(&second_set).count() as f64;
+
+        let first: MinHash<u64, 256> = first_set.iter().collect();
+        let second: MinHash<u64, 256> = second_set.iter().collect();
+        let first_quantized = first.quantize::<16>();
+        let second_quantized = second.quantize::<16>();
+
+        assert_eq!(first_quantized.as_bytes().len(), 256 * 16 / 8);
+
+        full_error += (first.estimate_jaccard_index(&second) - ground_truth).abs();
+        quantized_error +=
+            (first_quantized.estimate_jaccard_index(&second_quantized) - ground_truth).abs();
+    }
+
+    full_error /= PAIRS as f64;
+    quantized_error /= PAIRS as f64;
+
+    assert!(
+        quantized_error < full_error + 0.01,
+        "The mean absolute error of the quantized MinHash ({}) should be close to the one of the full precision one ({}).",
+        quantized_error,
+        full_error
+    );
+}
+
+#[test]
+fn test_quantized_sizes() {
+    let minhash: MinHash<u64, 100> = (0..1000_u64).collect();
+    assert_eq!(minhash.quantize::<8>().as_bytes().len(), 100);
+    assert_eq!(minhash.quantize::<16>().as_bytes().len(), 200);
+    assert_eq!(minhash.quantize::<32>().as_bytes().len(), 400);
+    assert_eq!(minhash.quantize::<32>().memory(), 100 * 32);
+}
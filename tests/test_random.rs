@@ -0,0 +1,29 @@
+//! This test module checks the random MinHashes generated with the rand crate.
+#![cfg(feature = "rand")]
+use minhash_rs::prelude::*;
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+#[test]
+pub fn test_random_self_jaccard() {
+    let mut rng = SmallRng::seed_from_u64(0x0123456789ABCDEF);
+    for _ in 0..100 {
+        let small: MinHash<u8, 128> = rng.gen();
+        let large = MinHash::<u64, 256>::random(&mut rng);
+
+        assert_eq!(small.estimate_jaccard_index(&small), 1.0);
+        assert_eq!(large.estimate_jaccard_index(&large), 1.0);
+
+        let mut union = large;
+        union |= &MinHash::random(&mut rng);
+        assert_eq!(union.estimate_jaccard_index(&union), 1.0);
+        assert!(union.iter().zip(large.iter()).all(|(u, l)| u <= l));
+    }
+}
+
+#[test]
+pub fn test_random_uniformity() {
+    let mut rng = SmallRng::seed_from_u64(42);
+    let minhash: MinHash<u32, 4096> = rng.gen();
+    let mean = minhash.normalized_words().sum::<f64>() / 4096.0;
+    assert!((mean - 0.5).abs() < 0.02, "{}", mean);
+}
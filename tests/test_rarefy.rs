@@ -0,0 +1,38 @@
+//! This test module checks the rarefaction of MinHashes.
+use minhash_rs::prelude::*;
+
+#[test]
+pub fn test_rarefy_sparsity() {
+    let mut minhash: MinHash<u32, 1024> = (0..10_000_u64).collect();
+    let original = minhash;
+
+    minhash.rarefy(u32::MAX);
+    assert_eq!(minhash, original);
+
+    minhash.rarefy(u32::MAX / 10_000);
+    let fill_fraction = minhash.fill_fraction();
+    assert!(fill_fraction < original.fill_fraction());
+    // Each word is kept with probability 1 - (1 - 1 / 10000)^10000 ~ 1 - 1 / e.
+    assert!((fill_fraction - 0.632).abs() < 0.05, "{}", fill_fraction);
+
+    minhash.rarefy(u32::MAX / 10_000_000);
+    assert!(minhash.fill_fraction() < 0.01);
+}
+
+#[test]
+pub fn test_rarefy_jaccard() {
+    let mut first: MinHash<u32, 1024> = (0..1000_u64).collect();
+    let mut second: MinHash<u32, 1024> = (500..1500_u64).collect();
+    let expected = 500.0 / 1500.0;
+
+    let full_error = (first.estimate_jaccard_index(&second) - expected).abs();
+
+    let threshold = u32::MAX / 1500;
+    first.rarefy(threshold);
+    second.rarefy(threshold);
+
+    let rarefied_error = (first.estimate_jaccard_index_dense(&second) - expected).abs();
+
+    assert!(full_error < 0.05, "{}", full_error);
+    assert!(rarefied_error < 0.08, "{}", rarefied_error);
+}
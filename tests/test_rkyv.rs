@@ -0,0 +1,31 @@
+//! This test module checks that archived MinHashArrays can be used without deserialization.
+#![cfg(feature = "rkyv")]
+use minhash_rs::prelude::*;
+use rkyv::Deserialize;
+
+#[test]
+pub fn test_rkyv_archived_array() {
+    let mut array = MinHashArray::<u64, 128, 8>::new();
+    for (i, minhash) in array.iter_mut().enumerate() {
+        for value in 0..1000_u64 {
+            minhash.insert_with_siphashes13(value + 100 * i as u64);
+        }
+    }
+
+    let bytes = rkyv::to_bytes::<_, 16384>(&array).unwrap();
+    let archived = unsafe { rkyv::archived_root::<MinHashArray<u64, 128, 8>>(&bytes) };
+
+    for i in 0..8 {
+        assert_eq!(archived.get(i), &array[i]);
+        for j in 0..8 {
+            assert_eq!(
+                archived.get(i).estimate_jaccard_index(archived.get(j)),
+                array[i].estimate_jaccard_index(&array[j])
+            );
+        }
+    }
+
+    let deserialized: MinHashArray<u64, 128, 8> =
+        archived.deserialize(&mut rkyv::Infallible).unwrap();
+    assert_eq!(deserialized, array);
+}
@@ -0,0 +1,59 @@
+//! This test module checks the Jaccard estimate corrected for the collisions of the saturated words.
+use minhash_rs::prelude::*;
+
+/// Returns the naive and the corrected estimates for two sets of the provided size and offset.
+fn estimates(seed: u64, size: u64, offset: u64) -> (f64, f64) {
+    let start = seed * 100_000_000;
+    let first: MinHash<u8, 1024> = (start..start + size).collect();
+    let second: MinHash<u8, 1024> = (start + offset..start + offset + size).collect();
+    (
+        first.estimate_jaccard_index(&second),
+        first.estimate_jaccard_index_saturation_corrected(&second),
+    )
+}
+
+#[test]
+pub fn test_saturation_corrected_distinct_sets() {
+    for seed in 0..5 {
+        let (naive, corrected) = estimates(seed, 500, 1_000_000);
+        // Most words of both MinHashes are saturated, so the naive estimate is far from zero.
+        assert!(naive > 0.5, "{}", naive);
+        assert!(corrected < 0.1, "{}", corrected);
+    }
+}
+
+#[test]
+pub fn test_saturation_corrected_overlapping_sets() {
+    // Sets of 500 elements sharing 250 of them, whose Jaccard index is one third.
+    for seed in 0..5 {
+        let (naive, corrected) = estimates(seed, 500, 250);
+        assert!(naive > 0.7, "{}", naive);
+        assert!((corrected - 1.0 / 3.0).abs() < 0.1, "{}", corrected);
+    }
+}
+
+#[test]
+pub fn test_saturation_corrected_unsaturated() {
+    // With wide words the collisions are negligible, and the two estimates coincide.
+    let first: MinHash<u64, 256> = (0..1000_u64).collect();
+    let second: MinHash<u64, 256> = (500..1500_u64).collect();
+
+    assert_eq!(
+        first.estimate_jaccard_index(&second),
+        first.estimate_jaccard_index_saturation_corrected(&second)
+    );
+}
+
+#[test]
+pub fn test_saturation_corrected_empty() {
+    let empty = MinHash::<u8, 64>::new();
+    let minhash: MinHash<u8, 64> = (0..100_u64).collect();
+
+    assert!(empty
+        .estimate_jaccard_index_saturation_corrected(&empty)
+        .is_nan());
+    assert_eq!(
+        empty.estimate_jaccard_index_saturation_corrected(&minhash),
+        0.0
+    );
+}
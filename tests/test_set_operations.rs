@@ -0,0 +1,109 @@
+//! This test module checks that the estimated cardinalities of the union, intersection
+//! and symmetric difference of two sets track the true values.
+use minhash_rs::prelude::*;
+
+#[test]
+pub fn test_set_operations_cardinalities() {
+    const SIZE: u64 = 10_000;
+
+    for (seed, overlap) in [0.1, 0.3, 0.5, 0.7, 0.9].into_iter().enumerate() {
+        let offset = seed as u64 * 1_000_000;
+        let shift = ((1.0 - overlap) * SIZE as f64) as u64;
+        let first: MinHash<u64, 1024> = (offset..offset + SIZE).collect();
+        let second: MinHash<u64, 1024> = (offset + shift..offset + shift + SIZE).collect();
+
+        let union = (SIZE + shift) as f64;
+        let intersection = (SIZE - shift) as f64;
+        let difference = (2 * shift) as f64;
+
+        let estimated_union = first.estimate_union_cardinality(&second);
+        let estimated_intersection = first.estimate_intersection_cardinality(&second);
+        let estimated_difference = first.estimate_symmetric_difference_cardinality(&second);
+
+        // The tolerance is relative to the union, which bounds all of the cardinalities.
+        let tolerance = 0.08 * union;
+        assert!(
+            (estimated_union - union).abs() < tolerance,
+            "Union: {} vs {}",
+            estimated_union,
+            union
+        );
+        assert!(
+            (estimated_intersection - intersection).abs() < tolerance,
+            "Intersection: {} vs {}",
+            estimated_intersection,
+            intersection
+        );
+        assert!(
+            (estimated_difference - difference).abs() < tolerance,
+            "Difference: {} vs {}",
+            estimated_difference,
+            difference
+        );
+    }
+}
+
+#[test]
+pub fn test_union_cardinality_of_three_sets() {
+    use std::collections::HashSet;
+
+    let sets: [HashSet<u64>; 3] = [
+        (0..5_000).collect(),
+        (3_000..9_000).collect(),
+        (7_000..12_000).step_by(2).collect(),
+    ];
+    let union = sets[0].union(&sets[1]).count() + sets[2].difference(&sets[1]).count();
+    let minhashes: Vec<MinHash<u64, 1024>> = sets.iter().map(|set| set.iter().collect()).collect();
+
+    let estimated_union =
+        minhashes[0].estimate_union_cardinality_many(&[&minhashes[1], &minhashes[2]]);
+
+    assert!(
+        (estimated_union - union as f64).abs() < 0.08 * union as f64,
+        "Union: {} vs {}",
+        estimated_union,
+        union
+    );
+    assert_eq!(
+        estimated_union,
+        minhashes.iter().copied().union_all().estimate_cardinality()
+    );
+}
+
+#[test]
+// The MinHash is Copy, but the point of the test is to exercise the borrowing operators.
+#[allow(clippy::op_ref, clippy::clone_on_copy)]
+pub fn test_bitor_by_reference() {
+    let first: MinHash<u32, 256> = (0..1000_u64).collect();
+    let second: MinHash<u32, 256> = (500..1500_u64).collect();
+    let (first_copy, second_copy) = (first.clone(), second.clone());
+
+    let union = &first | &second;
+
+    assert_eq!(first, first_copy);
+    assert_eq!(second, second_copy);
+    assert_eq!(union, first.clone() | second.clone());
+    assert_eq!(union, first.clone() | &second);
+    assert_eq!(union, (0..1500_u64).collect::<MinHash<u32, 256>>());
+
+    let mut merged = first;
+    merged |= second;
+    assert_eq!(merged, union);
+}
+
+#[test]
+pub fn test_merge_into() {
+    let first: MinHash<u32, 256> = (0..1000_u64).collect();
+    let second: MinHash<u32, 256> = (500..1500_u64).collect();
+    let first_before = first;
+    let second_before = second;
+
+    // The previous content of the output is overwritten.
+    let mut out: MinHash<u32, 256> = (10_000..10_100_u64).collect();
+    first.merge_into(&second, &mut out);
+
+    assert_eq!(out, first | second);
+    assert_eq!(out, (0..1500_u64).collect());
+    assert_eq!(first, first_before);
+    assert_eq!(second, second_before);
+}
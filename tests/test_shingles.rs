@@ -0,0 +1,33 @@
+//! This test module checks the insertion of the shingles of a document.
+use minhash_rs::prelude::*;
+
+const DOCUMENT: &[u8] = b"MinHash estimates the Jaccard index of two sets from their signatures.";
+
+#[test]
+pub fn test_shingles_match_manual_insertion() {
+    let mut shingled = MinHash::<u32, 256>::new();
+    shingled.insert_shingles(DOCUMENT, 3);
+
+    let mut manual = MinHash::<u32, 256>::new();
+    for i in 0..DOCUMENT.len() - 2 {
+        manual.insert_with_siphashes13(&DOCUMENT[i..i + 3]);
+    }
+
+    assert_eq!(shingled, manual);
+}
+
+#[test]
+pub fn test_shingles_short_data() {
+    let mut minhash = MinHash::<u64, 128>::new();
+    minhash.insert_shingles(b"ab", 3);
+    assert!(minhash.is_empty());
+
+    minhash.insert_shingles(b"abc", 3);
+    assert!(minhash.may_contain_value_with_siphashes13(&b"abc"[..]));
+}
+
+#[test]
+#[should_panic]
+pub fn test_shingles_zero_length() {
+    MinHash::<u64, 128>::new().insert_shingles(DOCUMENT, 0);
+}
@@ -0,0 +1,48 @@
+//! This test module checks that the SIMD Jaccard estimation is identical to the scalar one.
+#![cfg(feature = "simd")]
+use minhash_rs::prelude::*;
+
+/// Returns pseudo-random words from the provided state, drawn from a small alphabet
+/// so that the two MinHashes share a random fraction of their words.
+fn random_words<Word: Copy, const PERMUTATIONS: usize>(
+    state: &mut u64,
+    alphabet: &[Word],
+) -> [Word; PERMUTATIONS] {
+    core::array::from_fn(|_| {
+        *state = state.wrapping_add(0x9e3779b97f4a7c15);
+        alphabet[(state.splitmix() % alphabet.len() as u64) as usize]
+    })
+}
+
+fn check_simd<const PERMUTATIONS: usize>(seed: u64) {
+    let mut state = seed;
+    for alphabet_size in [1, 2, 3, 10, 256] {
+        let alphabet: Vec<u8> = (0..alphabet_size).map(|i| (i * 97 % 256) as u8).collect();
+        let first = MinHash::<u8, PERMUTATIONS>::from_words(random_words(&mut state, &alphabet));
+        let second = MinHash::<u8, PERMUTATIONS>::from_words(random_words(&mut state, &alphabet));
+        assert_eq!(
+            first.estimate_jaccard_index_simd(&second),
+            first.estimate_jaccard_index(&second)
+        );
+
+        let alphabet: Vec<u16> = (0..alphabet_size).map(|i| (i * 9973) as u16).collect();
+        let first = MinHash::<u16, PERMUTATIONS>::from_words(random_words(&mut state, &alphabet));
+        let second = MinHash::<u16, PERMUTATIONS>::from_words(random_words(&mut state, &alphabet));
+        assert_eq!(
+            first.estimate_jaccard_index_simd(&second),
+            first.estimate_jaccard_index(&second)
+        );
+    }
+}
+
+#[test]
+pub fn test_simd_matches_scalar() {
+    for seed in 0..10 {
+        check_simd::<1>(seed);
+        check_simd::<31>(seed);
+        check_simd::<32>(seed);
+        check_simd::<100>(seed);
+        check_simd::<8192>(seed);
+        check_simd::<8195>(seed);
+    }
+}
@@ -0,0 +1,27 @@
+//! Test suite for the histogram of the pairwise similarities of a MinHashArray.
+use minhash_rs::prelude::*;
+
+#[test]
+fn test_similarity_histogram_near_duplicates() {
+    const N: usize = 20;
+    let mut array = MinHashArray::<u32, 256, N>::new();
+    for (i, minhash) in array.iter_mut().enumerate() {
+        // The first half are near-duplicates of the same set, while
+        // the second half are disjoint sets.
+        let offset = if i < N / 2 {
+            i as u64
+        } else {
+            100_000 * i as u64
+        };
+        for value in offset..offset + 2000 {
+            minhash.insert_with_siphashes13(value);
+        }
+    }
+
+    let histogram = array.similarity_histogram(10);
+    let near_duplicate_pairs = (N / 2) * (N / 2 - 1) / 2;
+
+    assert_eq!(histogram.iter().sum::<usize>(), N * (N - 1) / 2);
+    assert_eq!(histogram[9], near_duplicate_pairs);
+    assert_eq!(histogram[0], N * (N - 1) / 2 - near_duplicate_pairs);
+}
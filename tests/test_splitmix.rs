@@ -0,0 +1,101 @@
+//! Test suite checking the avalanche of the SplitMix finalizers of each word width.
+use minhash_rs::prelude::*;
+
+/// Returns the average number of output bits flipped by flipping a single input bit.
+fn average_flipped_bits<Word>(inputs: impl Iterator<Item = Word>, bits: u32) -> f64
+where
+    Word: SplitMix + Copy + core::ops::BitXor<Output = Word> + core::ops::Shl<u32, Output = Word>,
+    Word: From<u8> + Into<u64>,
+{
+    let mut flipped = 0_u64;
+    let mut trials = 0_u64;
+    for input in inputs {
+        let output = input.splitmix();
+        for bit in 0..bits {
+            let other = (input ^ (Word::from(1) << bit)).splitmix();
+            flipped += (output ^ other).into().count_ones() as u64;
+            trials += 1;
+        }
+    }
+    flipped as f64 / trials as f64
+}
+
+/// Returns deterministic pseudo-random inputs.
+fn inputs() -> impl Iterator<Item = u64> {
+    (0..10_000_u64).map(|i| i.splitmix())
+}
+
+#[test]
+fn test_splitmix_avalanche() {
+    let average = average_flipped_bits(inputs().map(|x| x as u32), 32);
+    assert!((average - 16.0).abs() < 0.5, "u32: {}", average);
+    let average = average_flipped_bits(0..=u16::MAX, 16);
+    assert!((average - 8.0).abs() < 0.25, "u16: {}", average);
+    let average = average_flipped_bits(0..=u8::MAX, 8);
+    assert!((average - 4.0).abs() < 0.25, "u8: {}", average);
+
+    let mut flipped = 0;
+    for input in inputs() {
+        for bit in 0..64 {
+            flipped += (input.splitmix() ^ (input ^ (1 << bit)).splitmix()).count_ones();
+        }
+    }
+    let average = flipped as f64 / (10_000.0 * 64.0);
+    assert!((average - 32.0).abs() < 0.5, "u64: {}", average);
+}
+
+#[test]
+fn test_narrow_splitmix_is_bijective() {
+    let mut seen = vec![false; 1 << 16];
+    for input in 0..=u16::MAX {
+        seen[input.splitmix() as usize] = true;
+    }
+    assert!(seen.iter().all(|&seen| seen));
+
+    let mut seen = [false; 256];
+    for input in 0..=u8::MAX {
+        seen[input.splitmix() as usize] = true;
+    }
+    assert!(seen.iter().all(|&seen| seen));
+}
+
+#[test]
+pub fn test_splitmix64_reference() {
+    // Reference values of the SplitMix64 generator seeded with 1234567.
+    let mut generator = SplitMix64::new(1234567);
+    for expected in [
+        6457827717110365317,
+        3203168211198807973,
+        9817491932198370423,
+        4593380528125082431,
+        16408922859458223821,
+    ] {
+        assert_eq!(generator.next_u64(), expected);
+    }
+}
+
+#[test]
+pub fn test_splitmix64_seeds_permutations() {
+    // The hashes of the permutations are the outputs of the generator seeded with the hash of the value.
+    let seed = 0x0123456789ABCDEF;
+    let mut generator = SplitMix64::new(seed);
+    assert!(HashIter::<u64>::from_hash(seed, 64)
+        .eq(core::iter::repeat_with(|| generator.next_u64()).take(64)));
+}
+
+#[test]
+pub fn test_zero_seed_is_not_degenerate() {
+    // A value hashing to zero must still yield distinct permutations, as the
+    // generator increments its state before mixing it.
+    let hashes: Vec<u64> = HashIter::<u64>::from_hash(0, 128).collect();
+    let mut distinct = hashes.clone();
+    distinct.sort_unstable();
+    distinct.dedup();
+    assert_eq!(distinct.len(), hashes.len());
+    assert!(!hashes.contains(&0));
+
+    let mut minhash = MinHash::<u32, 128>::new();
+    minhash.insert_prehashed(0);
+    assert!(minhash.iter().any(|word| *word != minhash[0]));
+    assert!(minhash.may_contain_prehashed(0));
+}
@@ -0,0 +1,43 @@
+//! Test suite for the raw byte conversion of the MinHash.
+use minhash_rs::prelude::*;
+
+fn check_round_trip<Word, const PERMUTATIONS: usize>()
+where
+    Word: ToBytes + Maximal + Min + XorShift + Copy + Eq + core::fmt::Debug,
+    u64: Primitive<Word>,
+{
+    let minhash: MinHash<Word, PERMUTATIONS> = (0..1000_u64).collect();
+
+    let le_bytes = minhash.to_le_bytes();
+    let be_bytes = minhash.to_be_bytes();
+    assert_eq!(le_bytes.len(), MinHash::<Word, PERMUTATIONS>::MEMORY_BYTES);
+    assert_eq!(be_bytes.len(), MinHash::<Word, PERMUTATIONS>::MEMORY_BYTES);
+
+    assert_eq!(MinHash::from_le_bytes(&le_bytes), Some(minhash));
+    assert_eq!(MinHash::from_be_bytes(&be_bytes), Some(minhash));
+
+    // The two endiannesses are the byte-reversal of each other within each word.
+    for (le, be) in le_bytes
+        .chunks_exact(Word::BYTES)
+        .zip(be_bytes.chunks_exact(Word::BYTES))
+    {
+        assert!(le.iter().eq(be.iter().rev()));
+    }
+
+    assert_eq!(
+        MinHash::<Word, PERMUTATIONS>::from_le_bytes(&le_bytes[..le_bytes.len() - 1]),
+        None
+    );
+    let mut longer = be_bytes.clone();
+    longer.push(0);
+    assert_eq!(MinHash::<Word, PERMUTATIONS>::from_be_bytes(&longer), None);
+}
+
+#[test]
+fn test_to_bytes_round_trip() {
+    check_round_trip::<u8, 64>();
+    check_round_trip::<u16, 64>();
+    check_round_trip::<u32, 64>();
+    check_round_trip::<u64, 64>();
+    check_round_trip::<u128, 64>();
+}
@@ -0,0 +1,27 @@
+//! This test module checks that the Jaccard index estimated by truncated MinHashes
+//! tracks the one estimated by the full MinHashes within the expected larger variance.
+use minhash_rs::prelude::*;
+
+#[test]
+pub fn test_truncated_jaccard() {
+    for seed in 0..20_u64 {
+        let offset = seed * 1_000_000;
+        let shift = 100 + seed * 40;
+        let first: MinHash<u64, 8192> = (offset..offset + 1000).collect();
+        let second: MinHash<u64, 8192> = (offset + shift..offset + 1000 + shift).collect();
+
+        let full = first.estimate_jaccard_index(&second);
+        let truncated = first
+            .truncate::<512>()
+            .estimate_jaccard_index(&second.truncate::<512>());
+
+        // The variance of the truncated estimate dominates the one of the full estimate.
+        let std = (full * (1.0 - full) / 512.0).sqrt();
+        assert!(
+            (truncated - full).abs() < 4.0 * std,
+            "Full: {}, truncated: {}",
+            full,
+            truncated
+        );
+    }
+}
@@ -0,0 +1,40 @@
+//! This test module checks the fallible conversions from slices and vectors of words.
+use minhash_rs::prelude::*;
+
+#[test]
+pub fn test_try_from_slice() {
+    let minhash: MinHash<u32, 128> = (0..1000_u64).collect();
+    let words: &[u32] = minhash.as_ref();
+
+    assert_eq!(MinHash::<u32, 128>::try_from(words), Ok(minhash));
+    assert_eq!(
+        MinHash::<u32, 128>::try_from(&words[..100]),
+        Err(LengthMismatch {
+            expected: 128,
+            found: 100
+        })
+    );
+}
+
+#[test]
+pub fn test_try_from_vec() {
+    let minhash: MinHash<u16, 64> = (0..1000_u64).collect();
+    let words: Vec<u16> = minhash.iter().copied().collect();
+
+    assert_eq!(MinHash::<u16, 64>::try_from(words.clone()), Ok(minhash));
+
+    let mut longer = words;
+    longer.push(0);
+    let error = MinHash::<u16, 64>::try_from(longer).unwrap_err();
+    assert_eq!(
+        error,
+        LengthMismatch {
+            expected: 64,
+            found: 65
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "Expected 64 words, one per permutation, but 65 were provided."
+    );
+}
@@ -0,0 +1,30 @@
+//! This test module checks that the uniformity test of the minima accepts
+//! well-built MinHashes and rejects artificially clustered ones.
+use minhash_rs::prelude::*;
+
+#[test]
+pub fn test_uniformity_pvalue() {
+    let mut rejected = 0;
+    for seed in 0..100_u64 {
+        let offset = seed * 1_000_000;
+        let minhash: MinHash<u64, 1024> = (offset..offset + 10_000).collect();
+        if minhash.uniformity_pvalue() < 0.01 {
+            rejected += 1;
+        }
+
+        // We cluster the minima onto four values, as a degenerate permutation scheme would.
+        let mut words = minhash.into_words();
+        for i in 0..1024 {
+            words[i] = words[i - i % 256];
+        }
+        let clustered = MinHash::from_words(words);
+        assert!(
+            clustered.uniformity_pvalue() < 0.01,
+            "The clustered MinHash {} was not rejected.",
+            seed
+        );
+    }
+
+    // At the 1% significance level, we expect about one well-built MinHash to be rejected.
+    assert!(rejected <= 5, "Rejected {} well-built MinHashes.", rejected);
+}
@@ -0,0 +1,53 @@
+//! This test module checks that the WeightedMinHash estimates the generalized
+//! Jaccard index of weighted sets within a few standard deviations.
+use minhash_rs::prelude::*;
+
+/// Returns the exact generalized Jaccard index of two weight vectors over the same features.
+fn weighted_jaccard(first: &[f64], second: &[f64]) -> f64 {
+    let (minima, maxima) = first
+        .iter()
+        .zip(second.iter())
+        .fold((0.0, 0.0), |(minima, maxima), (l, r)| {
+            (minima + l.min(*r), maxima + l.max(*r))
+        });
+    minima / maxima
+}
+
+#[test]
+pub fn test_weighted_minhash() {
+    const PERMUTATIONS: usize = 1024;
+
+    for seed in 0..20_u64 {
+        // Deterministic weights in [0, 10), some of which are zero.
+        let mut state = seed;
+        let mut weight = || {
+            state = state.wrapping_add(0x9e3779b97f4a7c15);
+            let value = (state.splitmix() % 100) as f64 / 10.0;
+            if value < 2.0 {
+                0.0
+            } else {
+                value
+            }
+        };
+        let first: Vec<f64> = (0..20).map(|_| weight()).collect();
+        let second: Vec<f64> = (0..20).map(|_| weight()).collect();
+
+        let mut first_minhash = WeightedMinHash::<u64, PERMUTATIONS>::new();
+        let mut second_minhash = WeightedMinHash::<u64, PERMUTATIONS>::new();
+        for (feature, (l, r)) in first.iter().zip(second.iter()).enumerate() {
+            first_minhash.insert_weighted(feature, *l);
+            second_minhash.insert_weighted(feature, *r);
+        }
+
+        let exact = weighted_jaccard(&first, &second);
+        let estimate = first_minhash.estimate_weighted_jaccard(&second_minhash);
+        let std = (exact * (1.0 - exact) / PERMUTATIONS as f64).sqrt();
+
+        assert!(
+            (estimate - exact).abs() < 4.0 * std + 1e-9,
+            "Exact: {}, estimate: {}",
+            exact,
+            estimate
+        );
+    }
+}
@@ -0,0 +1,46 @@
+//! This test module checks the diagnostics on the words of a MinHash.
+use minhash_rs::prelude::*;
+
+#[test]
+pub fn test_word_diagnostics() {
+    let mut minhash = MinHash::<u16, 64>::new();
+
+    assert_eq!(minhash.fill_fraction(), 0.0);
+    assert_eq!(minhash.min_word(), u16::MAX);
+    assert_eq!(minhash.max_word(), u16::MAX);
+
+    let mut previous_fill_fraction = 0.0;
+    for i in 0..100_u64 {
+        minhash.insert_with_siphashes13(i);
+        let fill_fraction = minhash.fill_fraction();
+        assert!(fill_fraction >= previous_fill_fraction);
+        assert!(minhash.min_word() <= minhash.max_word());
+        previous_fill_fraction = fill_fraction;
+    }
+
+    assert_eq!(minhash.fill_fraction(), 1.0);
+    assert!(minhash.max_word() < u16::MAX);
+}
+
+#[test]
+pub fn test_word_counts() {
+    let mut minhash = MinHash::<u8, 256>::new();
+    assert_eq!(minhash.unset_word_count(), 256);
+    assert_eq!(minhash.saturated_word_count(), 0);
+
+    let mut previous_saturated = 0;
+    for chunk in 0..20_u64 {
+        for i in chunk * 100..(chunk + 1) * 100 {
+            minhash.insert_with_siphashes13(i);
+        }
+        let saturated = minhash.saturated_word_count();
+        assert!(saturated >= previous_saturated);
+        assert!(saturated + minhash.unset_word_count() <= 256);
+        assert_eq!(
+            minhash.unset_word_count(),
+            ((1.0 - minhash.fill_fraction()) * 256.0).round() as usize
+        );
+        previous_saturated = saturated;
+    }
+    assert!(previous_saturated > 0);
+}
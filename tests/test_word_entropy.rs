@@ -0,0 +1,39 @@
+//! This test module checks the diagnostics on the diversity of the words of a MinHash.
+use minhash_rs::prelude::*;
+
+#[test]
+pub fn test_word_entropy_diverse() {
+    let minhash: MinHash<u32, 256> = (0..100_000_u64).collect();
+
+    assert!(minhash.distinct_word_count() > 250);
+    assert!(minhash.word_entropy() > 7.9);
+    assert!(minhash.word_entropy() <= 8.0);
+}
+
+#[test]
+pub fn test_word_entropy_degenerate() {
+    // Permutations that are all equal yield the same word everywhere.
+    let degenerate = MinHash::<u32, 256>::from_words([42; 256]);
+    assert_eq!(degenerate.distinct_word_count(), 1);
+    assert_eq!(degenerate.word_entropy(), 0.0);
+
+    // A single element has independent words under independent permutations,
+    // while the empty MinHash has a single maximal word.
+    let mut single = MinHash::<u32, 256>::new();
+    assert_eq!(single.distinct_word_count(), 1);
+    single.insert_with_siphashes13(42);
+    assert!(single.distinct_word_count() > 250);
+
+    // Two alternating words have exactly one bit of entropy.
+    let alternating = MinHash::<u8, 64>::from_words(core::array::from_fn(|i| (i % 2) as u8));
+    assert_eq!(alternating.distinct_word_count(), 2);
+    assert!((alternating.word_entropy() - 1.0).abs() < 1e-12);
+}
+
+#[test]
+pub fn test_word_entropy_saturated_small_words() {
+    // Small words saturate to zero on large sets, losing their diversity.
+    let saturated: MinHash<u8, 256> = (0..100_000_u64).collect();
+    assert_eq!(saturated.distinct_word_count(), 1);
+    assert_eq!(saturated.word_entropy(), 0.0);
+}
@@ -0,0 +1,95 @@
+//! Test suite checking the period of the narrow XorShift generators and the
+//! accuracy of the MinHash with narrow words.
+use std::collections::HashSet;
+
+use minhash_rs::prelude::*;
+
+#[test]
+fn test_u8_xorshift_has_full_period() {
+    for seed in 1..=u8::MAX {
+        let mut state = seed;
+        let mut seen = [false; 256];
+        for _ in 0..255 {
+            seen[state.xorshift() as usize] = true;
+        }
+        assert_eq!(state, seed);
+        assert!(!seen[0]);
+        assert!(seen[1..].iter().all(|&seen| seen), "Seed {}", seed);
+    }
+    assert_eq!(0_u8.xorshift(), 0);
+}
+
+#[test]
+fn test_u16_xorshift_has_full_period() {
+    let mut state = 1_u16;
+    let mut seen = vec![false; 1 << 16];
+    for _ in 0..u16::MAX {
+        seen[state.xorshift() as usize] = true;
+    }
+    assert_eq!(state, 1);
+    assert!(!seen[0]);
+    assert!(seen[1..].iter().all(|&seen| seen));
+}
+
+/// Returns the composition of the two linear maps over GF(2), given as the images of the basis vectors.
+fn compose(outer: &[u128; 128], inner: &[u128; 128]) -> [u128; 128] {
+    core::array::from_fn(|bit| {
+        (0..128)
+            .filter(|row| inner[bit] >> row & 1 == 1)
+            .fold(0, |image, row| image ^ outer[row])
+    })
+}
+
+/// Returns the linear map raised to the provided power.
+fn power(map: &[u128; 128], mut exponent: u128) -> [u128; 128] {
+    let mut result: [u128; 128] = core::array::from_fn(|bit| 1 << bit);
+    let mut base = *map;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = compose(&base, &result);
+        }
+        base = compose(&base, &base);
+        exponent >>= 1;
+    }
+    result
+}
+
+#[test]
+fn test_u128_xorshift_has_full_period() {
+    // The XorShift step is linear over GF(2), and it has the full period 2^128 - 1
+    // if and only if its matrix has exactly that multiplicative order, i.e. it is the
+    // identity when raised to 2^128 - 1, and not when raised to any maximal divisor.
+    let map: [u128; 128] = core::array::from_fn(|bit| (1_u128 << bit).xorshift());
+    let identity: [u128; 128] = core::array::from_fn(|bit| 1 << bit);
+    let period = u128::MAX;
+    // The prime factors of 2^128 - 1 = (2^64 - 1) * (2^64 + 1).
+    let factors: [u128; 9] = [3, 5, 17, 257, 641, 65537, 274177, 6700417, 67280421310721];
+    assert_eq!(factors.iter().product::<u128>(), period);
+
+    assert_eq!(power(&map, period), identity);
+    for factor in factors {
+        assert_ne!(power(&map, period / factor), identity, "Factor {}", factor);
+    }
+}
+
+#[test]
+fn test_u8_minhash_jaccard_accuracy() {
+    const PAIRS: usize = 50;
+    let mut error = 0.0;
+    for pair in 0..PAIRS as u64 {
+        let offset = pair * 10_000;
+        let first_set: HashSet<u64> = (offset..offset + 20).collect();
+        let second_set: HashSet<u64> = (offset + 10..offset + 30).collect();
+        let ground_truth = first_set.intersection(&second_set).count() as f64
+            / first_set.union(&second_set).count() as f64;
+
+        let first: MinHash<u8, 256> = first_set.iter().collect();
+        let second: MinHash<u8, 256> = second_set.iter().collect();
+        error += (first.estimate_jaccard_index(&second) - ground_truth).abs();
+    }
+    error /= PAIRS as f64;
+
+    // With 256 permutations the standard error at a Jaccard of 1/3 is about 0.03,
+    // and the sets are kept small so that the 8 bit words do not saturate.
+    assert!(error < 0.06, "Mean absolute error: {}", error);
+}
@@ -0,0 +1,34 @@
+//! Test suite for the XxHash64 based insertion.
+#![cfg(feature = "xxhash")]
+use minhash_rs::prelude::*;
+
+#[test]
+fn test_xxhash64_test_vector() {
+    let mut minhash = MinHash::<u64, 4>::new();
+    minhash.insert_with_xxhash64(42_u64);
+    assert_eq!(
+        minhash.into_words(),
+        [
+            0x4206f5ea5df8f4d1,
+            0xb08c65aaef39a293,
+            0xa926a992531bf019,
+            0x8dab991b44d99818,
+        ]
+    );
+
+    // Narrower words keep the highest bits of the hashes.
+    let mut minhash = MinHash::<u16, 4>::new();
+    minhash.insert_with_xxhash64(42_u64);
+    assert_eq!(minhash.into_words(), [0x4206, 0xb08c, 0xa926, 0x8dab]);
+}
+
+#[test]
+fn test_xxhash64_may_contain() {
+    let mut minhash = MinHash::<u32, 128>::new();
+    for value in 0..1000_u64 {
+        minhash.insert_with_xxhash64(value);
+    }
+    for value in 0..1000_u64 {
+        assert!(minhash.may_contain_value_with_xxhash64(value));
+    }
+}